@@ -0,0 +1,156 @@
+use core::fmt;
+
+use crate::vector3::Vector3;
+use crate::float_trait::Float;
+
+// Axis-aligned bounding box, described by its minimum and maximum corner.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Aabb3<T> where T: Float {
+    pub min: Vector3<T>,
+    pub max: Vector3<T>
+}
+
+impl<T: Float> Aabb3<T> {
+    pub fn new(min: Vector3<T>, max: Vector3<T>) -> Aabb3<T> {
+        Aabb3 {
+            min,
+            max
+        }
+    }
+
+    // Returns the smallest box enclosing all the given points, or None if there are no points.
+    pub fn from_points(points: &[Vector3<T>]) -> Option<Aabb3<T>> {
+        let first = *points.first()?;
+
+        let mut aabb = Aabb3::new(first, first);
+
+        for point in &points[1..] {
+            aabb = aabb.expand_to_point(*point);
+        }
+
+        Some(aabb)
+    }
+
+    pub fn expand_to_point(self, point: Vector3<T>) -> Aabb3<T> {
+        Aabb3 {
+            min: Vector3::new(
+                if point.x < self.min.x { point.x } else { self.min.x },
+                if point.y < self.min.y { point.y } else { self.min.y },
+                if point.z < self.min.z { point.z } else { self.min.z }),
+            max: Vector3::new(
+                if point.x > self.max.x { point.x } else { self.max.x },
+                if point.y > self.max.y { point.y } else { self.max.y },
+                if point.z > self.max.z { point.z } else { self.max.z })
+        }
+    }
+
+    pub fn center(self) -> Vector3<T> {
+        let half = T::one() / (T::one() + T::one());
+
+        (self.min + self.max).scalar_multiplication(half)
+    }
+
+    pub fn half_extents(self) -> Vector3<T> {
+        let half = T::one() / (T::one() + T::one());
+
+        (self.max - self.min).scalar_multiplication(half)
+    }
+
+    pub fn contains_point(self, point: Vector3<T>) -> bool {
+        point.x >= self.min.x && point.x <= self.max.x &&
+        point.y >= self.min.y && point.y <= self.max.y &&
+        point.z >= self.min.z && point.z <= self.max.z
+    }
+
+    pub fn intersects(self, other: Aabb3<T>) -> bool {
+        self.min.x <= other.max.x && self.max.x >= other.min.x &&
+        self.min.y <= other.max.y && self.max.y >= other.min.y &&
+        self.min.z <= other.max.z && self.max.z >= other.min.z
+    }
+}
+
+impl<T: Float + fmt::Display> fmt::Display for Aabb3<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "min ")?;
+        fmt::Display::fmt(&self.min, f)?;
+        write!(f, ", max ")?;
+        fmt::Display::fmt(&self.max, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_points() {
+        // Arrange
+        let points = [
+            Vector3::<f64>::new(1.0, -2.0, 3.0),
+            Vector3::<f64>::new(-1.0, 4.0, 0.0),
+            Vector3::<f64>::new(0.0, 0.0, 5.0)
+        ];
+
+        // Act
+        let aabb = Aabb3::from_points(&points).unwrap();
+
+        // Assert
+        assert_eq!(aabb.min.x, -1.0);
+        assert_eq!(aabb.min.y, -2.0);
+        assert_eq!(aabb.min.z, 0.0);
+        assert_eq!(aabb.max.x, 1.0);
+        assert_eq!(aabb.max.y, 4.0);
+        assert_eq!(aabb.max.z, 5.0);
+    }
+
+    #[test]
+    fn test_from_no_points() {
+        // Act
+        let aabb = Aabb3::<f64>::from_points(&[]);
+
+        // Assert
+        assert!(aabb.is_none());
+    }
+
+    #[test]
+    fn test_center_and_half_extents() {
+        // Arrange
+        let aabb = Aabb3::new(Vector3::<f64>::new(-1.0, 0.0, 2.0), Vector3::<f64>::new(3.0, 2.0, 4.0));
+
+        // Act
+        let center = aabb.center();
+        let half_extents = aabb.half_extents();
+
+        // Assert
+        assert_eq!(center.x, 1.0);
+        assert_eq!(center.y, 1.0);
+        assert_eq!(center.z, 3.0);
+        assert_eq!(half_extents.x, 2.0);
+        assert_eq!(half_extents.y, 1.0);
+        assert_eq!(half_extents.z, 1.0);
+    }
+
+    #[test]
+    fn test_contains_point() {
+        // Arrange
+        let aabb = Aabb3::new(Vector3::<f64>::new(0.0, 0.0, 0.0), Vector3::<f64>::new(1.0, 1.0, 1.0));
+
+        // Act & Assert
+        assert!(aabb.contains_point(Vector3::new(0.5, 0.5, 0.5)));
+        assert!(!aabb.contains_point(Vector3::new(1.5, 0.5, 0.5)));
+    }
+
+    #[test]
+    fn test_intersects() {
+        // Arrange
+        let aabb_a = Aabb3::new(Vector3::<f64>::new(0.0, 0.0, 0.0), Vector3::<f64>::new(1.0, 1.0, 1.0));
+        let aabb_b = Aabb3::new(Vector3::<f64>::new(0.5, 0.5, 0.5), Vector3::<f64>::new(2.0, 2.0, 2.0));
+        let aabb_c = Aabb3::new(Vector3::<f64>::new(1.5, 0.0, 0.0), Vector3::<f64>::new(2.0, 1.0, 1.0));
+
+        // Act & Assert
+        assert!(aabb_a.intersects(aabb_b));
+        assert!(!aabb_a.intersects(aabb_c));
+    }
+}
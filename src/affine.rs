@@ -0,0 +1,191 @@
+use core::fmt;
+use core::convert::TryFrom;
+use core::ops::Mul;
+
+use crate::vector3::Vector3;
+use crate::float_trait::Float;
+use crate::matrix::Matrix;
+use crate::rigid_transform::RigidTransform;
+
+// An affine transformation: a linear part (rotation, scale, shear) followed by a translation.
+// It is what a Matrix4x4 holds whenever its bottom row is (0, 0, 0, 1), which is every transformation except projections.
+// Storing only the 3x4 part makes the bottom row impossible to get wrong, and composing, inverting and transforming points all skip the work on it.
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Affine3<T> where T: Float {
+    pub linear: Matrix<T, 3, 3>,
+    pub translation: Vector3<T>
+}
+
+// The error returned when converting a matrix whose bottom row is not (0, 0, 0, 1) into an Affine3, such as a perspective projection
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct NotAffine<T> {
+    pub bottom_row: [T; 4]
+}
+
+impl<T: fmt::Display> fmt::Display for NotAffine<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [m30, m31, m32, m33] = &self.bottom_row;
+        write!(f, "the bottom row of an affine matrix must be [0, 0, 0, 1], but it is [{}, {}, {}, {}]", m30, m31, m32, m33)
+    }
+}
+
+impl<T: fmt::Debug + fmt::Display> core::error::Error for NotAffine<T> {}
+
+impl<T: Float> Affine3<T> {
+    pub fn new(linear: Matrix<T, 3, 3>, translation: Vector3<T>) -> Affine3<T> {
+        Affine3 {
+            linear,
+            translation
+        }
+    }
+
+    pub fn identity() -> Affine3<T> {
+        Affine3::from_translation(Vector3::new(T::zero(), T::zero(), T::zero()))
+    }
+
+    pub fn from_translation(translation: Vector3<T>) -> Affine3<T> {
+        Affine3::new(Matrix::identity(), translation)
+    }
+
+    pub fn from_scale(scale: Vector3<T>) -> Affine3<T> {
+        let linear = Matrix::from_rows([
+            [scale.x, T::zero(), T::zero()],
+            [T::zero(), scale.y, T::zero()],
+            [T::zero(), T::zero(), scale.z]]);
+
+        Affine3::new(linear, Vector3::new(T::zero(), T::zero(), T::zero()))
+    }
+
+    // Transforms a point, which is affected by translation
+    pub fn transform_point(&self, point: Vector3<T>) -> Vector3<T> {
+        self.transform_vector(point) + self.translation
+    }
+
+    // Transforms a direction, which is NOT affected by translation
+    pub fn transform_vector(&self, vector: Vector3<T>) -> Vector3<T> {
+        self.linear.mul(vector)
+    }
+
+    // Only the 3x3 linear part needs a real inverse. The translation is then undone by moving back along the inverted linear part,
+    // which is much cheaper than inverting a full 4x4 matrix. Returns None if the linear part is singular, for example a scale of zero.
+    pub fn inverse(&self) -> Option<Affine3<T>> {
+        let linear = self.linear.inverse()?;
+        let translation = linear.mul(self.translation);
+
+        Some(Affine3::new(linear, Vector3::new(T::zero(), T::zero(), T::zero()) - translation))
+    }
+
+    pub fn to_matrix(&self) -> Matrix<T, 4, 4> {
+        Matrix::from(*self)
+    }
+}
+
+// Composes two transforms, so that "a * b" applies b first and then a, like the equivalent matrices
+impl<T: Float> Mul for Affine3<T> {
+    type Output = Affine3<T>;
+
+    fn mul(self, other: Affine3<T>) -> Affine3<T> {
+        Affine3::new(self.linear.mul(other.linear), self.transform_point(other.translation))
+    }
+}
+
+impl<T: Float> From<RigidTransform<T>> for Affine3<T> {
+    fn from(transform: RigidTransform<T>) -> Affine3<T> {
+        Affine3::new(transform.rotation, transform.translation)
+    }
+}
+
+// Works for Matrix4x4, which is a Matrix<f32, 4, 4>
+impl<T: Float> From<Affine3<T>> for Matrix<T, 4, 4> {
+    fn from(affine: Affine3<T>) -> Matrix<T, 4, 4> {
+        let translation = [affine.translation.x, affine.translation.y, affine.translation.z];
+
+        Matrix::from_fn(|row, column| {
+            match (row, column) {
+                (3, 3) => T::one(),
+                (3, _) => T::zero(),
+                (_, 3) => translation[row],
+                _ => affine.linear[[row, column]]
+            }
+        })
+    }
+}
+
+// The bottom row must be exactly (0, 0, 0, 1). Multiplying affine matrices keeps those zeros and that one exact, so no tolerance is needed.
+impl<T: Float> TryFrom<Matrix<T, 4, 4>> for Affine3<T> {
+    type Error = NotAffine<T>;
+
+    fn try_from(matrix: Matrix<T, 4, 4>) -> Result<Affine3<T>, NotAffine<T>> {
+        let bottom_row = [matrix[[3, 0]], matrix[[3, 1]], matrix[[3, 2]], matrix[[3, 3]]];
+
+        if bottom_row != [T::zero(), T::zero(), T::zero(), T::one()] {
+            return Err(NotAffine { bottom_row });
+        }
+
+        Ok(Affine3::new(
+            Matrix::from_fn(|row, column| matrix[[row, column]]),
+            Vector3::new(matrix[[0, 3]], matrix[[1, 3]], matrix[[2, 3]])))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vector2::Vector2;
+    use crate::matrix4x4::Matrix4x4;
+    use float_cmp::approx_eq;
+
+    fn assert_vectors_equal(a: Vector3<f32>, b: Vector3<f32>) {
+        assert!( approx_eq!(f32, (a - b).length(), 0.0, epsilon = 0.0001) );
+    }
+
+    #[test]
+    fn test_matches_matrix4x4() {
+        // Arrange
+        let matrix = Matrix4x4::identity().translate(Vector2::new(3.0, -2.0)).rotate(0.0, 0.0, 0.7).scale(2.0, 4.0, 0.5);
+        let point = Vector3::new(1.0, 2.0, 3.0);
+
+        // Act
+        let affine = Affine3::try_from(matrix).unwrap();
+
+        // Assert
+        assert_vectors_equal(affine.transform_point(point), matrix.transform_point(point));
+        assert_vectors_equal(affine.transform_vector(point), matrix.transform_vector(point));
+        assert_eq!(Matrix4x4::from(affine), matrix);
+    }
+
+    #[test]
+    fn test_composition_and_inverse() {
+        // Arrange
+        let a = Affine3::from_translation(Vector3::new(1.0, 2.0, 3.0));
+        let b = Affine3::from_scale(Vector3::new(2.0, 2.0, 4.0));
+        let point = Vector3::new(1.0, 1.0, 1.0);
+
+        // Act
+        let composed = a * b;
+        let inverse = composed.inverse().unwrap();
+
+        // Assert
+        assert_vectors_equal(composed.transform_point(point), Vector3::new(3.0, 4.0, 7.0));
+        assert_vectors_equal(inverse.transform_point(composed.transform_point(point)), point);
+        assert_eq!(composed.to_matrix(), a.to_matrix().mul(b.to_matrix()));
+        assert!(Affine3::from_scale(Vector3::new(1.0, 0.0, 1.0)).inverse().is_none());
+    }
+
+    #[test]
+    fn test_projection_is_not_affine() {
+        // Arrange
+        let mut matrix = Matrix4x4::identity();
+        matrix[[3, 2]] = -1.0;
+        matrix[[3, 3]] = 0.0;
+
+        // Act
+        let result = Affine3::try_from(matrix);
+
+        // Assert
+        assert_eq!(result, Err(NotAffine { bottom_row: [0.0, 0.0, -1.0, 0.0] }));
+        assert_eq!(format!("{}", result.unwrap_err()), "the bottom row of an affine matrix must be [0, 0, 0, 1], but it is [0, 0, -1, 0]");
+    }
+}
@@ -0,0 +1,66 @@
+use bytemuck::{Pod, Zeroable};
+
+use crate::matrix::Matrix;
+
+// Pod ("plain old data") allows casting slices of vectors and matrices to and from bytes with bytemuck::cast_slice,
+// which is what is needed when filling vertex and uniform buffers.
+// SAFETY: Matrices are "repr(C)" and consist only of elements of a single Pod type, so they have no padding,
+// and every bit pattern is a valid value. Zero is a valid value for the same reason.
+// This covers matrices of any size, so Matrix4x4, Matrix3x3, Vector2, Vector3 and Vector4 are all covered.
+macro_rules! impl_matrix_pod {
+    ($($scalar:ty),+) => {
+        $(
+            unsafe impl<const R: usize, const C: usize> Zeroable for Matrix<$scalar, R, C> {}
+            unsafe impl<const R: usize, const C: usize> Pod for Matrix<$scalar, R, C> {}
+        )+
+    };
+}
+
+impl_matrix_pod!(f32, i32, u32);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vector2::Vector2;
+    use crate::vector3::Vector3;
+    use crate::vector4::Vector4;
+    use crate::matrix4x4::Matrix4x4;
+
+    #[test]
+    fn test_cast_vertices_to_bytes() {
+        // Arrange
+        let vertices = [Vector3::<f32>::new(1.0, 2.0, 3.0), Vector3::<f32>::new(4.0, 5.0, 6.0)];
+
+        // Act
+        let bytes: &[u8] = bytemuck::cast_slice(&vertices);
+        let floats: &[f32] = bytemuck::cast_slice(&vertices);
+
+        // Assert
+        assert_eq!(bytes.len(), 24);
+        assert_eq!(floats, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    }
+
+    #[test]
+    fn test_cast_matrix_to_floats() {
+        // Arrange
+        let matrix = Matrix4x4::identity().translate(Vector2::new(5.0, 7.0));
+
+        // Act
+        let floats: &[f32; 16] = bytemuck::cast_ref(&matrix);
+
+        // Assert
+        // Column-major, so the translation is in the last four elements
+        assert_eq!(floats[12], 5.0);
+        assert_eq!(floats[13], 7.0);
+        assert_eq!(floats[15], 1.0);
+    }
+
+    #[test]
+    fn test_zeroed() {
+        // Act
+        let vector: Vector4<u32> = Zeroable::zeroed();
+
+        // Assert
+        assert_eq!(vector, Vector4::new(0, 0, 0, 0));
+    }
+}
@@ -0,0 +1,97 @@
+use core::ops::{Add, Sub, Mul};
+
+use crate::vector2::Vector2;
+use crate::vector3::Vector3;
+use crate::vector4::Vector4;
+use crate::matrix4x4::Matrix4x4;
+
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy> From<cgmath::Vector2<T>> for Vector2<T> {
+    fn from(vector: cgmath::Vector2<T>) -> Vector2<T> {
+        Vector2::new(vector.x, vector.y)
+    }
+}
+
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy> From<Vector2<T>> for cgmath::Vector2<T> {
+    fn from(vector: Vector2<T>) -> cgmath::Vector2<T> {
+        cgmath::Vector2::new(vector.x, vector.y)
+    }
+}
+
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy> From<cgmath::Vector3<T>> for Vector3<T> {
+    fn from(vector: cgmath::Vector3<T>) -> Vector3<T> {
+        Vector3::new(vector.x, vector.y, vector.z)
+    }
+}
+
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy> From<Vector3<T>> for cgmath::Vector3<T> {
+    fn from(vector: Vector3<T>) -> cgmath::Vector3<T> {
+        cgmath::Vector3::new(vector.x, vector.y, vector.z)
+    }
+}
+
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy> From<cgmath::Vector4<T>> for Vector4<T> {
+    fn from(vector: cgmath::Vector4<T>) -> Vector4<T> {
+        Vector4::new(vector.x, vector.y, vector.z, vector.w)
+    }
+}
+
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy> From<Vector4<T>> for cgmath::Vector4<T> {
+    fn from(vector: Vector4<T>) -> cgmath::Vector4<T> {
+        cgmath::Vector4::new(vector.x, vector.y, vector.z, vector.w)
+    }
+}
+
+// Careful: unlike Matrix4x4::new, cgmath::Matrix4::new takes its elements in column-major order.
+// Its fields "x", "y", "z" and "w" are the columns.
+impl From<cgmath::Matrix4<f32>> for Matrix4x4 {
+    fn from(matrix: cgmath::Matrix4<f32>) -> Matrix4x4 {
+        let (x, y, z, w) = (matrix.x, matrix.y, matrix.z, matrix.w);
+
+        Matrix4x4::new(
+            x.x, y.x, z.x, w.x,
+            x.y, y.y, z.y, w.y,
+            x.z, y.z, z.z, w.z,
+            x.w, y.w, z.w, w.w)
+    }
+}
+
+impl From<Matrix4x4> for cgmath::Matrix4<f32> {
+    fn from(matrix: Matrix4x4) -> cgmath::Matrix4<f32> {
+        let column = |c: usize| cgmath::Vector4::new(matrix[[0, c]], matrix[[1, c]], matrix[[2, c]], matrix[[3, c]]);
+
+        cgmath::Matrix4::from_cols(column(0), column(1), column(2), column(3))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vector_round_trip() {
+        // Arrange
+        let vector_a = Vector4::<f32>::new(1.0, 2.0, 3.0, 4.0);
+
+        // Act
+        let cgmath_vector: cgmath::Vector4<f32> = vector_a.into();
+        let converted_vector: Vector4<f32> = cgmath_vector.into();
+
+        // Assert
+        assert_eq!(cgmath_vector, cgmath::Vector4::new(1.0, 2.0, 3.0, 4.0));
+        assert_eq!(converted_vector, vector_a);
+    }
+
+    #[test]
+    fn test_matrix_round_trip() {
+        // Arrange
+        let matrix_a = Matrix4x4::identity().translate(Vector2::new(5.0, 7.0));
+
+        // Act
+        let cgmath_matrix: cgmath::Matrix4<f32> = matrix_a.into();
+        let converted_matrix: Matrix4x4 = cgmath_matrix.into();
+
+        // Assert
+        assert_eq!(cgmath_matrix, cgmath::Matrix4::from_translation(cgmath::Vector3::new(5.0, 7.0, 0.0)));
+        assert!(converted_matrix == matrix_a);
+    }
+}
@@ -0,0 +1,619 @@
+use alloc::vec::Vec;
+use core::fmt;
+use core::convert::TryFrom;
+use core::ops::{Add, Sub, Mul, Neg, Index, IndexMut};
+
+use crate::sqrt_trait::Sqrt;
+use crate::float_trait::Float;
+use crate::matrix::{Matrix, Vector};
+
+// A matrix whose size is only known at run-time, such as the system of equations for fitting a plane through a list of points.
+// The elements are stored in a Vec in column-major order, the same order as the fixed-size Matrix.
+// Prefer the fixed-size Matrix for anything up to 4x4, since it lives on the stack and its sizes are checked when compiling.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct DMatrix<T> where T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy {
+    rows: usize,
+    columns: usize,
+    elements: Vec<T>
+}
+
+// A column vector whose length is only known at run-time
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct DVector<T> where T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy {
+    elements: Vec<T>
+}
+
+// The error returned when converting a dynamic matrix or vector into a fixed-size one of a different size.
+// The sizes are given as (rows, columns).
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct DimensionMismatch {
+    pub expected: (usize, usize),
+    pub found: (usize, usize)
+}
+
+impl fmt::Display for DimensionMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "expected a {}x{} matrix but found a {}x{} matrix", self.expected.0, self.expected.1, self.found.0, self.found.1)
+    }
+}
+
+impl core::error::Error for DimensionMismatch {}
+
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy> DMatrix<T> {
+    pub fn from_element(rows: usize, columns: usize, element: T) -> DMatrix<T> {
+        DMatrix {
+            rows,
+            columns,
+            elements: alloc::vec![element; rows * columns]
+        }
+    }
+
+    // Creates a matrix by calling "f" with the row and column of every element
+    pub fn from_fn<F: FnMut(usize, usize) -> T>(rows: usize, columns: usize, mut f: F) -> DMatrix<T> {
+        let mut elements = Vec::with_capacity(rows * columns);
+
+        for column in 0..columns {
+            for row in 0..rows {
+                elements.push(f(row, column));
+            }
+        }
+
+        DMatrix {
+            rows,
+            columns,
+            elements
+        }
+    }
+
+    // The elements in column-major order. Panics if there are not exactly rows * columns of them.
+    pub fn from_cols_slice(rows: usize, columns: usize, elements: &[T]) -> DMatrix<T> {
+        assert_eq!(elements.len(), rows * columns, "A {}x{} matrix needs {} elements, but {} were given!", rows, columns, rows * columns, elements.len());
+
+        DMatrix {
+            rows,
+            columns,
+            elements: elements.to_vec()
+        }
+    }
+
+    // The elements in row-major order, so that the call reads like the matrix it creates.
+    // Panics if there are not exactly rows * columns of them.
+    pub fn from_rows_slice(rows: usize, columns: usize, elements: &[T]) -> DMatrix<T> {
+        assert_eq!(elements.len(), rows * columns, "A {}x{} matrix needs {} elements, but {} were given!", rows, columns, rows * columns, elements.len());
+
+        DMatrix::from_fn(rows, columns, |row, column| elements[row * columns + column])
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    // The number of rows and columns
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, self.columns)
+    }
+
+    pub fn is_square(&self) -> bool {
+        self.rows == self.columns
+    }
+
+    // All the elements in column-major order
+    pub fn as_slice(&self) -> &[T] {
+        &self.elements
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.elements
+    }
+
+    // Since the elements are stored column by column, a column can be borrowed without copying
+    pub fn column(&self, column: usize) -> &[T] {
+        if column >= self.columns {
+            panic!("You requested column {}, but the matrix only has {} columns!", column, self.columns);
+        }
+
+        &self.elements[column * self.rows..(column + 1) * self.rows]
+    }
+
+    pub fn column_mut(&mut self, column: usize) -> &mut [T] {
+        if column >= self.columns {
+            panic!("You requested column {}, but the matrix only has {} columns!", column, self.columns);
+        }
+
+        &mut self.elements[column * self.rows..(column + 1) * self.rows]
+    }
+
+    pub fn row(&self, row: usize) -> DVector<T> {
+        if row >= self.rows {
+            panic!("You requested row {}, but the matrix only has {} rows!", row, self.rows);
+        }
+
+        DVector::from_fn(self.columns, |column| self[[row, column]])
+    }
+
+    pub fn transpose(&self) -> DMatrix<T> {
+        DMatrix::from_fn(self.columns, self.rows, |row, column| self[[column, row]])
+    }
+
+    // Multiplies an R x C matrix with a C x K matrix, which gives an R x K matrix.
+    // Unlike the fixed-size Matrix, the sizes can only be checked at run-time, so this panics if the number of columns of "self" does not match the number of rows of "other".
+    // Like indexing out of range, that is a bug in the calling code and not something to recover from.
+    pub fn mul(&self, other: &DMatrix<T>) -> DMatrix<T> {
+        if self.columns != other.rows {
+            panic!("Cannot multiply a {}x{} matrix with a {}x{} matrix!", self.rows, self.columns, other.rows, other.columns);
+        }
+
+        if self.columns == 0 {
+            panic!("Cannot multiply matrices with zero columns, since there is no zero value to start the sums from!");
+        }
+
+        DMatrix::from_fn(self.rows, other.columns, |row, column| {
+            let mut sum = self[[row, 0]] * other[[0, column]];
+
+            for k in 1..self.columns {
+                sum = sum + self[[row, k]] * other[[k, column]];
+            }
+
+            sum
+        })
+    }
+
+    pub fn mul_vector(&self, vector: &DVector<T>) -> DVector<T> {
+        if self.columns != vector.len() {
+            panic!("Cannot multiply a {}x{} matrix with a vector of length {}!", self.rows, self.columns, vector.len());
+        }
+
+        DVector::from(self.mul(&DMatrix::from(vector.clone())).elements)
+    }
+
+    pub fn scalar_multiplication(&self, scalar: T) -> DMatrix<T> {
+        self.map(|element| element * scalar)
+    }
+
+    pub fn map<U, F>(&self, f: F) -> DMatrix<U>
+    where U: Add<Output = U> + Sub<Output = U> + Mul<Output = U> + Copy, F: FnMut(T) -> U {
+        DMatrix {
+            rows: self.rows,
+            columns: self.columns,
+            elements: self.elements.iter().copied().map(f).collect()
+        }
+    }
+
+    // Copies the elements of the given fixed-size matrix into this one, with its upper-left corner at (row, column)
+    pub fn set_block<const R: usize, const C: usize>(&mut self, row: usize, column: usize, block: &Matrix<T, R, C>) {
+        for block_column in 0..C {
+            for block_row in 0..R {
+                self[[row + block_row, column + block_column]] = block[[block_row, block_column]];
+            }
+        }
+    }
+
+    fn zip_with<F: Fn(T, T) -> T>(&self, other: &DMatrix<T>, operation: &str, f: F) -> DMatrix<T> {
+        if self.shape() != other.shape() {
+            panic!("Cannot {} a {}x{} matrix and a {}x{} matrix!", operation, self.rows, self.columns, other.rows, other.columns);
+        }
+
+        DMatrix {
+            rows: self.rows,
+            columns: self.columns,
+            elements: self.elements.iter().zip(&other.elements).map(|(a, b)| f(*a, *b)).collect()
+        }
+    }
+}
+
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy + Default> DMatrix<T> {
+    pub fn zeros(rows: usize, columns: usize) -> DMatrix<T> {
+        DMatrix::from_element(rows, columns, T::default())
+    }
+}
+
+impl<T: Float> DMatrix<T> {
+    pub fn identity(size: usize) -> DMatrix<T> {
+        DMatrix::from_fn(size, size, |row, column| if row == column { T::one() } else { T::zero() })
+    }
+}
+
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy> Index<[usize; 2]> for DMatrix<T> {
+    type Output = T;
+
+    // Indexed as [row, column]. Like the fixed-size Matrix, this panics when the index is out of range.
+    fn index(&self, index: [usize; 2]) -> &T {
+        let [requested_row, requested_column] = index;
+
+        if requested_column >= self.columns {
+            panic!("You requested column {}, but the matrix only has {} columns!", requested_column, self.columns);
+        }
+
+        if requested_row >= self.rows {
+            panic!("You requested row {}, but the matrix only has {} rows!", requested_row, self.rows);
+        }
+
+        &self.elements[requested_column * self.rows + requested_row]
+    }
+}
+
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy> IndexMut<[usize; 2]> for DMatrix<T> {
+    fn index_mut(&mut self, index: [usize; 2]) -> &mut T {
+        let [requested_row, requested_column] = index;
+
+        if requested_column >= self.columns {
+            panic!("You requested column {}, but the matrix only has {} columns!", requested_column, self.columns);
+        }
+
+        if requested_row >= self.rows {
+            panic!("You requested row {}, but the matrix only has {} rows!", requested_row, self.rows);
+        }
+
+        &mut self.elements[requested_column * self.rows + requested_row]
+    }
+}
+
+// The operators are implemented for references, so that large matrices do not have to be cloned to be combined
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy> Add for &DMatrix<T> {
+    type Output = DMatrix<T>;
+
+    fn add(self, other: &DMatrix<T>) -> DMatrix<T> {
+        self.zip_with(other, "add", |a, b| a + b)
+    }
+}
+
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy> Sub for &DMatrix<T> {
+    type Output = DMatrix<T>;
+
+    fn sub(self, other: &DMatrix<T>) -> DMatrix<T> {
+        self.zip_with(other, "subtract", |a, b| a - b)
+    }
+}
+
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy> Mul for &DMatrix<T> {
+    type Output = DMatrix<T>;
+
+    fn mul(self, other: &DMatrix<T>) -> DMatrix<T> {
+        DMatrix::mul(self, other)
+    }
+}
+
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy> Mul<&DVector<T>> for &DMatrix<T> {
+    type Output = DVector<T>;
+
+    fn mul(self, vector: &DVector<T>) -> DVector<T> {
+        self.mul_vector(vector)
+    }
+}
+
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy + Neg<Output = T>> Neg for &DMatrix<T> {
+    type Output = DMatrix<T>;
+
+    fn neg(self) -> DMatrix<T> {
+        self.map(|element| -element)
+    }
+}
+
+// Prints the size followed by the rows, for example "DMatrix2x3 [[1, 2, 3], [4, 5, 6]]"
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy + fmt::Debug> fmt::Debug for DMatrix<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "DMatrix{}x{} [", self.rows, self.columns)?;
+
+        for row in 0..self.rows {
+            if row > 0 {
+                write!(f, ", ")?;
+            }
+
+            f.debug_list().entries((0..self.columns).map(|column| self[[row, column]])).finish()?;
+        }
+
+        write!(f, "]")
+    }
+}
+
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy, const R: usize, const C: usize> From<Matrix<T, R, C>> for DMatrix<T> {
+    fn from(matrix: Matrix<T, R, C>) -> DMatrix<T> {
+        DMatrix::from_cols_slice(R, C, Matrix::as_flat_slice(core::slice::from_ref(&matrix)))
+    }
+}
+
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy, const R: usize, const C: usize> TryFrom<&DMatrix<T>> for Matrix<T, R, C> {
+    type Error = DimensionMismatch;
+
+    fn try_from(matrix: &DMatrix<T>) -> Result<Matrix<T, R, C>, DimensionMismatch> {
+        if matrix.shape() != (R, C) {
+            return Err(DimensionMismatch { expected: (R, C), found: matrix.shape() });
+        }
+
+        Ok(Matrix::from_fn(|row, column| matrix[[row, column]]))
+    }
+}
+
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy, const R: usize, const C: usize> TryFrom<DMatrix<T>> for Matrix<T, R, C> {
+    type Error = DimensionMismatch;
+
+    fn try_from(matrix: DMatrix<T>) -> Result<Matrix<T, R, C>, DimensionMismatch> {
+        Matrix::try_from(&matrix)
+    }
+}
+
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy> DVector<T> {
+    pub fn from_element(length: usize, element: T) -> DVector<T> {
+        DVector {
+            elements: alloc::vec![element; length]
+        }
+    }
+
+    pub fn from_fn<F: FnMut(usize) -> T>(length: usize, f: F) -> DVector<T> {
+        DVector {
+            elements: (0..length).map(f).collect()
+        }
+    }
+
+    pub fn from_slice(elements: &[T]) -> DVector<T> {
+        DVector {
+            elements: elements.to_vec()
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        &self.elements
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.elements
+    }
+
+    pub fn into_vec(self) -> Vec<T> {
+        self.elements
+    }
+
+    // Panics if the vectors have different lengths, or are empty
+    pub fn dot_product(&self, other: &DVector<T>) -> T {
+        if self.len() != other.len() {
+            panic!("Cannot take the dot product of vectors of length {} and {}!", self.len(), other.len());
+        }
+
+        let mut products = self.elements.iter().zip(&other.elements).map(|(a, b)| *a * *b);
+        let first = products.next().expect("Cannot take the dot product of empty vectors!");
+
+        products.fold(first, |sum, product| sum + product)
+    }
+
+    pub fn scalar_multiplication(&self, scalar: T) -> DVector<T> {
+        DVector {
+            elements: self.elements.iter().map(|element| *element * scalar).collect()
+        }
+    }
+}
+
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy + Default> DVector<T> {
+    pub fn zeros(length: usize) -> DVector<T> {
+        DVector::from_element(length, T::default())
+    }
+}
+
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy + Sqrt> DVector<T> {
+    pub fn length(&self) -> T {
+        self.dot_product(self).sqrt()
+    }
+}
+
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy> Index<usize> for DVector<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        &self.elements[index]
+    }
+}
+
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy> IndexMut<usize> for DVector<T> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        &mut self.elements[index]
+    }
+}
+
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy> Add for &DVector<T> {
+    type Output = DVector<T>;
+
+    fn add(self, other: &DVector<T>) -> DVector<T> {
+        DVector::from((&DMatrix::from(self.clone()) + &DMatrix::from(other.clone())).elements)
+    }
+}
+
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy> Sub for &DVector<T> {
+    type Output = DVector<T>;
+
+    fn sub(self, other: &DVector<T>) -> DVector<T> {
+        DVector::from((&DMatrix::from(self.clone()) - &DMatrix::from(other.clone())).elements)
+    }
+}
+
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy + fmt::Debug> fmt::Debug for DVector<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "DVector{} ", self.len())?;
+        f.debug_list().entries(&self.elements).finish()
+    }
+}
+
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy> From<Vec<T>> for DVector<T> {
+    fn from(elements: Vec<T>) -> DVector<T> {
+        DVector {
+            elements
+        }
+    }
+}
+
+// A vector is a matrix with a single column
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy> From<DVector<T>> for DMatrix<T> {
+    fn from(vector: DVector<T>) -> DMatrix<T> {
+        DMatrix {
+            rows: vector.len(),
+            columns: 1,
+            elements: vector.elements
+        }
+    }
+}
+
+// Only a matrix with a single column is a vector, so any other matrix gives an error instead of being flattened
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy> TryFrom<DMatrix<T>> for DVector<T> {
+    type Error = DimensionMismatch;
+
+    fn try_from(matrix: DMatrix<T>) -> Result<DVector<T>, DimensionMismatch> {
+        if matrix.columns != 1 {
+            return Err(DimensionMismatch { expected: (matrix.rows, 1), found: matrix.shape() });
+        }
+
+        Ok(DVector {
+            elements: matrix.elements
+        })
+    }
+}
+
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy, const N: usize> From<Vector<T, N>> for DVector<T> {
+    fn from(vector: Vector<T, N>) -> DVector<T> {
+        DVector::from_slice(&vector.to_array())
+    }
+}
+
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy, const N: usize> TryFrom<&DVector<T>> for Vector<T, N> {
+    type Error = DimensionMismatch;
+
+    fn try_from(vector: &DVector<T>) -> Result<Vector<T, N>, DimensionMismatch> {
+        if vector.len() != N {
+            return Err(DimensionMismatch { expected: (N, 1), found: (vector.len(), 1) });
+        }
+
+        Ok(Vector::from_array(core::array::from_fn(|index| vector[index])))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix4x4::Matrix4x4;
+    use crate::vector2::Vector2;
+
+    #[test]
+    fn test_construction_and_indexing() {
+        // Act
+        let matrix_a = DMatrix::from_rows_slice(2, 3, &[1, 2, 3, 4, 5, 6]);
+        let matrix_b = DMatrix::from_cols_slice(2, 3, &[1, 4, 2, 5, 3, 6]);
+
+        // Assert
+        assert_eq!(matrix_a, matrix_b);
+        assert_eq!(matrix_a.shape(), (2, 3));
+        assert_eq!(matrix_a[[1, 2]], 6);
+        assert_eq!(matrix_a.column(1), &[2, 5]);
+        assert_eq!(matrix_a.row(1).as_slice(), &[4, 5, 6]);
+        assert_eq!(matrix_a.as_slice(), &[1, 4, 2, 5, 3, 6]);
+        assert_eq!(format!("{:?}", matrix_a), "DMatrix2x3 [[1, 2, 3], [4, 5, 6]]");
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_index_out_of_range() {
+        // Arrange
+        let matrix = DMatrix::<f32>::zeros(2, 2);
+
+        // Act
+        let _ = matrix[[2, 0]];
+    }
+
+    #[test]
+    fn test_mul_and_transpose() {
+        // Arrange
+        let matrix_a = DMatrix::from_rows_slice(2, 3, &[1, 2, 3, 4, 5, 6]);
+        let matrix_b = DMatrix::from_rows_slice(3, 1, &[1, 0, 2]);
+        let vector = DVector::from(alloc::vec![1, 0, 2]);
+
+        // Act
+        let product = &matrix_a * &matrix_b;
+        let gram_matrix = &matrix_a.transpose() * &matrix_a;
+        let transformed_vector = &matrix_a * &vector;
+
+        // Assert
+        assert_eq!(product, DMatrix::from_rows_slice(2, 1, &[7, 16]));
+        assert_eq!(gram_matrix.shape(), (3, 3));
+        assert_eq!(gram_matrix[[0, 0]], 17);
+        assert_eq!(gram_matrix[[2, 1]], 36);
+        assert_eq!(transformed_vector.as_slice(), &[7, 16]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_mul_with_mismatched_sizes() {
+        // Arrange
+        let matrix_a = DMatrix::<f32>::zeros(2, 3);
+        let matrix_b = DMatrix::<f32>::zeros(2, 3);
+
+        // Act
+        let _ = &matrix_a * &matrix_b;
+    }
+
+    #[test]
+    fn test_element_wise_operations() {
+        // Arrange
+        let matrix_a = DMatrix::from_rows_slice(2, 2, &[1.0, 2.0, 3.0, 4.0]);
+        let identity = DMatrix::identity(2);
+
+        // Act & Assert
+        assert_eq!(&matrix_a + &identity, DMatrix::from_rows_slice(2, 2, &[2.0, 2.0, 3.0, 5.0]));
+        assert_eq!(&matrix_a - &identity, DMatrix::from_rows_slice(2, 2, &[0.0, 2.0, 3.0, 3.0]));
+        assert_eq!(-&matrix_a, matrix_a.scalar_multiplication(-1.0));
+    }
+
+    #[test]
+    fn test_fixed_size_conversions() {
+        // Arrange
+        let matrix = Matrix4x4::identity().translate(Vector2::new(5.0, 7.0));
+
+        // Act
+        let dynamic_matrix = DMatrix::from(matrix);
+        let converted_matrix = Matrix4x4::try_from(&dynamic_matrix);
+        let wrong_size = Matrix::<f32, 3, 4>::try_from(&dynamic_matrix);
+
+        // Assert
+        assert_eq!(dynamic_matrix[[0, 3]], 5.0);
+        assert_eq!(dynamic_matrix.as_slice(), matrix.as_cols_array());
+        assert_eq!(converted_matrix, Ok(matrix));
+        assert_eq!(wrong_size, Err(DimensionMismatch { expected: (3, 4), found: (4, 4) }));
+    }
+
+    #[test]
+    fn test_vector_operations() {
+        // Arrange
+        let vector_a = DVector::from(Vector::from_array([3.0, 4.0]));
+        let vector_b = DVector::from_slice(&[1.0, 2.0]);
+
+        // Act & Assert
+        assert_eq!(vector_a.length(), 5.0);
+        assert_eq!(vector_a.dot_product(&vector_b), 11.0);
+        assert_eq!((&vector_a + &vector_b).as_slice(), &[4.0, 6.0]);
+        assert_eq!((&vector_a - &vector_b).as_slice(), &[2.0, 2.0]);
+        assert_eq!(Vector::<f32, 2>::try_from(&vector_a).unwrap().to_array(), [3.0, 4.0]);
+        assert!(Vector::<f32, 3>::try_from(&vector_a).is_err());
+    }
+
+    #[test]
+    fn test_matrix_to_vector_conversion() {
+        // Arrange
+        let column = DMatrix::from_rows_slice(3, 1, &[1, 2, 3]);
+        let square = DMatrix::from_rows_slice(3, 3, &[1, 2, 3, 4, 5, 6, 7, 8, 9]);
+
+        // Act
+        let vector = DVector::try_from(column);
+        let flattened = DVector::try_from(square);
+
+        // Assert
+        assert_eq!(vector.unwrap().as_slice(), &[1, 2, 3]);
+        assert_eq!(flattened, Err(DimensionMismatch { expected: (3, 1), found: (3, 3) }));
+    }
+}
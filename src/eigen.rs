@@ -0,0 +1,197 @@
+use crate::vector3::Vector3;
+use crate::float_trait::Float;
+use crate::matrix::Matrix;
+
+// The eigen-decomposition of a symmetric 3x3 matrix A = V diag(eigenvalues) V^T.
+// The columns of V are the eigenvectors, which have unit length and are perpendicular to each other.
+// They are sorted by descending eigenvalue, and V is always a proper rotation (its determinant is +1, never -1),
+// so the eigenvectors can be used directly as the axes of a right-handed frame, such as the axes of an oriented bounding box.
+#[derive(Copy, Clone, Debug)]
+pub struct SymmetricEigen<T> where T: Float {
+    pub eigenvalues: [T; 3],
+    pub eigenvectors: Matrix<T, 3, 3>
+}
+
+impl<T: Float> SymmetricEigen<T> {
+    pub fn eigenvector(&self, index: usize) -> Vector3<T> {
+        self.eigenvectors.column(index)
+    }
+}
+
+impl<T: Float> Matrix<T, 3, 3> {
+    // Finds the eigenvalues and eigenvectors using the cyclic Jacobi method.
+    // Each step applies a rotation that zeroes one off-diagonal element, and the accumulated rotations form the eigenvectors.
+    // Only the upper triangle of the matrix is assumed to be meaningful, the matrix must be symmetric for the result to make sense.
+    // Jacobi is slower than the alternatives for large matrices, but for 3x3 it converges in a handful of sweeps and is very accurate,
+    // also when eigenvalues are repeated, which is common for the symmetric shapes that appear in geometry.
+    pub fn symmetric_eigen(&self) -> SymmetricEigen<T> {
+        let mut a = self.to_rows();
+        let mut v = Matrix::<T, 3, 3>::identity().to_rows();
+        let two = T::one() + T::one();
+
+        let mut norm = T::zero();
+
+        for row in &a {
+            for element in row {
+                norm = norm + *element * *element;
+            }
+        }
+
+        for _sweep in 0..32 {
+            let off_diagonal = a[0][1] * a[0][1] + a[0][2] * a[0][2] + a[1][2] * a[1][2];
+
+            // Converged when the off-diagonal elements are only rounding noise compared to the matrix as a whole
+            if off_diagonal <= norm * T::epsilon() * T::epsilon() {
+                break;
+            }
+
+            for &(p, q) in &[(0, 1), (0, 2), (1, 2)] {
+                if a[p][q] == T::zero() {
+                    continue;
+                }
+
+                let theta = (a[q][q] - a[p][p]) / (two * a[p][q]);
+                let sign = if theta >= T::zero() { T::one() } else { -T::one() };
+                let t = sign / (theta.abs() + (theta * theta + T::one()).sqrt());
+                let c = T::one() / (t * t + T::one()).sqrt();
+                let s = t * c;
+
+                for row in a.iter_mut() {
+                    let (akp, akq) = (row[p], row[q]);
+                    row[p] = c * akp - s * akq;
+                    row[q] = s * akp + c * akq;
+                }
+
+                let (row_p, row_q) = (a[p], a[q]);
+
+                for k in 0..3 {
+                    a[p][k] = c * row_p[k] - s * row_q[k];
+                    a[q][k] = s * row_p[k] + c * row_q[k];
+                }
+
+                for row in v.iter_mut() {
+                    let (vkp, vkq) = (row[p], row[q]);
+                    row[p] = c * vkp - s * vkq;
+                    row[q] = s * vkp + c * vkq;
+                }
+            }
+        }
+
+        let mut order = [0, 1, 2];
+
+        for i in 0..3 {
+            for j in (i + 1)..3 {
+                if a[order[j]][order[j]] > a[order[i]][order[i]] {
+                    order.swap(i, j);
+                }
+            }
+        }
+
+        let mut eigenvectors: Matrix<T, 3, 3> = Matrix::from_fn(|row, column| v[row][order[column]]);
+
+        // An eigenvector is only defined up to its sign, so flipping the last one turns a reflection into a rotation
+        if eigenvectors.determinant() < T::zero() {
+            for row in 0..3 {
+                eigenvectors[[row, 2]] = -eigenvectors[[row, 2]];
+            }
+        }
+
+        SymmetricEigen {
+            eigenvalues: [a[order[0]][order[0]], a[order[1]][order[1]], a[order[2]][order[2]]],
+            eigenvectors
+        }
+    }
+}
+
+// The mean of the points, and their covariance matrix, which describes how the points are spread out around the mean.
+// The eigenvectors of the covariance matrix are the principal axes of the points, and the eigenvalues are the variances along them.
+// Returns None if there are no points.
+pub fn covariance<T: Float>(points: &[Vector3<T>]) -> Option<(Vector3<T>, Matrix<T, 3, 3>)> {
+    if points.is_empty() {
+        return None;
+    }
+
+    let mut count = T::zero();
+    let mut mean = Vector3::new(T::zero(), T::zero(), T::zero());
+
+    for point in points {
+        mean = mean + *point;
+        count = count + T::one();
+    }
+
+    mean = mean.scalar_multiplication(T::one() / count);
+
+    let mut covariance = Matrix::from_cols([[T::zero(); 3]; 3]);
+
+    for point in points {
+        let offset = *point - mean;
+        let offset = [offset.x, offset.y, offset.z];
+
+        for row in 0..3 {
+            for column in 0..3 {
+                covariance[[row, column]] = covariance[[row, column]] + offset[row] * offset[column] / count;
+            }
+        }
+    }
+
+    Some((mean, covariance))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use float_cmp::approx_eq;
+
+    #[test]
+    fn test_symmetric_eigen() {
+        // Arrange
+        let matrix = Matrix::<f64, 3, 3>::from_rows([
+            [2.0, 1.0, 0.0],
+            [1.0, 2.0, 0.0],
+            [0.0, 0.0, 5.0]]);
+
+        // Act
+        let eigen = matrix.symmetric_eigen();
+
+        // Assert
+        assert!( approx_eq!(f64, eigen.eigenvalues[0], 5.0, epsilon = 0.0001) );
+        assert!( approx_eq!(f64, eigen.eigenvalues[1], 3.0, epsilon = 0.0001) );
+        assert!( approx_eq!(f64, eigen.eigenvalues[2], 1.0, epsilon = 0.0001) );
+        assert!( approx_eq!(f64, eigen.eigenvector(0).z.abs(), 1.0, epsilon = 0.0001) );
+        assert!( approx_eq!(f64, eigen.eigenvectors.determinant(), 1.0, epsilon = 0.0001) );
+
+        for index in 0..3 {
+            let eigenvector = eigen.eigenvectors.column(index);
+            let difference = matrix.mul(eigenvector) - eigenvector.scalar_multiplication(eigen.eigenvalues[index]);
+
+            assert!( approx_eq!(f64, difference.length(), 0.0, epsilon = 0.0001) );
+        }
+    }
+
+    #[test]
+    fn test_repeated_eigenvalues() {
+        // Arrange
+        let matrix = Matrix::<f32, 3, 3>::identity().scalar_multiplication(2.0);
+
+        // Act
+        let eigen = matrix.symmetric_eigen();
+
+        // Assert
+        assert_eq!(eigen.eigenvalues, [2.0, 2.0, 2.0]);
+        assert!( approx_eq!(f32, eigen.eigenvectors.determinant(), 1.0, epsilon = 0.0001) );
+    }
+
+    #[test]
+    fn test_covariance() {
+        // Arrange
+        let points = [Vector3::new(-1.0, 0.0, 0.0), Vector3::new(1.0, 0.0, 0.0), Vector3::new(0.0, 2.0, 0.0), Vector3::new(0.0, -2.0, 0.0)];
+
+        // Act
+        let (mean, covariance) = covariance(&points).unwrap();
+
+        // Assert
+        assert_eq!(mean, Vector3::new(0.0, 0.0, 0.0));
+        assert_eq!(covariance, Matrix::from_rows([[0.5, 0.0, 0.0], [0.0, 2.0, 0.0], [0.0, 0.0, 0.0]]));
+        assert!(super::covariance::<f32>(&[]).is_none());
+    }
+}
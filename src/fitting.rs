@@ -0,0 +1,289 @@
+use alloc::vec::Vec;
+
+use crate::vector2::Vector2;
+use crate::vector3::Vector3;
+use crate::float_trait::Float;
+use crate::plane::Plane;
+use crate::line::Line;
+use crate::eigen::covariance;
+use crate::dmatrix::{DMatrix, DVector};
+use crate::qr::solve_least_squares;
+
+// Fits shapes to noisy measurements. Every fit returns the residuals, the distance from each point to the fitted shape,
+// in the same order as the points, so outliers can be found and rejected before fitting again.
+
+#[derive(Clone, Debug)]
+pub struct PlaneFit<T> where T: Float {
+    pub plane: Plane<T>,
+    // The signed distance from each point to the plane
+    pub residuals: Vec<T>
+}
+
+#[derive(Clone, Debug)]
+pub struct LineFit<T> where T: Float {
+    // The line passes through the mean of the points, and its direction has unit length
+    pub line: Line<T>,
+    // The distance from each point to the line. A line has no inside or outside, so these are never negative.
+    pub residuals: Vec<T>
+}
+
+#[derive(Clone, Debug)]
+pub struct CircleFit<T> where T: Float {
+    pub center: Vector2<T>,
+    pub radius: T,
+    // The distance from each point to the circle, positive outside of it and negative inside
+    pub residuals: Vec<T>
+}
+
+impl<T: Float> PlaneFit<T> {
+    pub fn rms_error(&self) -> T {
+        root_mean_square(&self.residuals)
+    }
+}
+
+impl<T: Float> LineFit<T> {
+    pub fn rms_error(&self) -> T {
+        root_mean_square(&self.residuals)
+    }
+}
+
+impl<T: Float> CircleFit<T> {
+    pub fn rms_error(&self) -> T {
+        root_mean_square(&self.residuals)
+    }
+}
+
+fn root_mean_square<T: Float>(residuals: &[T]) -> T {
+    let mut sum = T::zero();
+    let mut count = T::zero();
+
+    for residual in residuals {
+        sum = sum + *residual * *residual;
+        count = count + T::one();
+    }
+
+    (sum / count).sqrt()
+}
+
+// A variance this much smaller than the largest one is only rounding noise, meaning that the points do not spread out in that direction at all
+fn is_negligible<T: Float>(variance: T, largest_variance: T) -> bool {
+    variance <= largest_variance * T::epsilon() * T::from_f64(16.0)
+}
+
+// Fits a plane by minimizing the sum of the squared distances from the points to it, which is also called total least squares.
+// Unlike fitting z = ax + by + c, this treats all three axes the same, so it also works for vertical planes.
+// The plane passes through the mean of the points, and its normal is the direction in which the points are spread out the least.
+// Returns None if there are fewer than 3 points, or if they are all on a line, since they then don't define a single plane.
+pub fn fit_plane<T: Float>(points: &[Vector3<T>]) -> Option<PlaneFit<T>> {
+    if points.len() < 3 {
+        return None;
+    }
+
+    // The eigenvalues of the covariance matrix are the variances of the points along its eigenvectors
+    let (mean, covariance) = covariance(points)?;
+    let eigen = covariance.symmetric_eigen();
+
+    if is_negligible(eigen.eigenvalues[1], eigen.eigenvalues[0]) {
+        return None;
+    }
+
+    let plane = Plane::from_point_normal(mean, eigen.eigenvector(2));
+
+    Some(PlaneFit {
+        plane,
+        residuals: points.iter().map(|point| plane.signed_distance(*point)).collect()
+    })
+}
+
+// Fits a line by minimizing the sum of the squared distances from the points to it.
+// The line passes through the mean of the points, in the direction in which the points are spread out the most.
+// Returns None if there are fewer than 2 points, or if they are all at the same position.
+pub fn fit_line<T: Float>(points: &[Vector3<T>]) -> Option<LineFit<T>> {
+    if points.len() < 2 {
+        return None;
+    }
+
+    let (mean, covariance) = covariance(points)?;
+    let eigen = covariance.symmetric_eigen();
+
+    // Points at the same position still get a tiny variance from rounding the mean, which is at most a few ulps of the coordinates themselves
+    if is_negligible(eigen.eigenvalues[0], mean.dot_product(mean)) {
+        return None;
+    }
+
+    let direction = eigen.eigenvector(0);
+
+    let residuals = points.iter().map(|point| {
+        let offset = *point - mean;
+        (offset - direction.scalar_multiplication(offset.dot_product(direction))).length()
+    }).collect();
+
+    Some(LineFit {
+        line: Line::new(mean, direction),
+        residuals
+    })
+}
+
+// Fits a circle using the Kåsa method. A circle is the set of points where x^2 + y^2 + Dx + Ey + F = 0,
+// which is linear in D, E and F, so they can be found with linear least squares instead of an iterative method.
+// The result minimizes the squared differences of the squared distances, rather than of the distances themselves,
+// which slightly favors smaller circles when the points only cover a short arc, but is exact for points on a circle.
+// Returns None if there are fewer than 3 points, or if they are all on a line.
+pub fn fit_circle_2d<T: Float>(points: &[Vector2<T>]) -> Option<CircleFit<T>> {
+    if points.len() < 3 {
+        return None;
+    }
+
+    // Fitting relative to the mean keeps the numbers small, which matters when the points are far away from the origin
+    let mut mean = Vector2::new(T::zero(), T::zero());
+    let mut count = T::zero();
+
+    for point in points {
+        mean = mean + *point;
+        count = count + T::one();
+    }
+
+    mean = mean.scalar_multiplication(T::one() / count);
+
+    let offsets: Vec<Vector2<T>> = points.iter().map(|point| *point - mean).collect();
+
+    let a = DMatrix::from_fn(points.len(), 3, |row, column| match column {
+        0 => offsets[row].x,
+        1 => offsets[row].y,
+        _ => T::one()
+    });
+    let b = DVector::from_fn(points.len(), |row| -offsets[row].dot_product(offsets[row]));
+
+    let solution = solve_least_squares(&a, &b)?;
+    let half = T::one() / (T::one() + T::one());
+    let center_offset = Vector2::new(-solution[0] * half, -solution[1] * half);
+    let radius_squared = center_offset.dot_product(center_offset) - solution[2];
+
+    if radius_squared <= T::zero() {
+        return None;
+    }
+
+    let radius = radius_squared.sqrt();
+
+    Some(CircleFit {
+        center: mean + center_offset,
+        radius,
+        residuals: offsets.iter().map(|offset| (*offset - center_offset).length() - radius).collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use float_cmp::approx_eq;
+
+    #[test]
+    fn test_fit_plane() {
+        // Arrange
+        // Points on the plane z = 2, with a small alternating offset
+        let points = [
+            Vector3::new(0.0, 0.0, 2.1),
+            Vector3::new(4.0, 0.0, 1.9),
+            Vector3::new(4.0, 4.0, 2.1),
+            Vector3::new(0.0, 4.0, 1.9)];
+
+        // Act
+        let fit = fit_plane(&points).unwrap();
+
+        // Assert
+        assert!( approx_eq!(f64, fit.plane.normal.z.abs(), 1.0, epsilon = 0.0001) );
+        assert!( approx_eq!(f64, fit.plane.signed_distance(Vector3::new(2.0, 2.0, 2.0)), 0.0, epsilon = 0.0001) );
+        assert!( approx_eq!(f64, fit.rms_error(), 0.1, epsilon = 0.0001) );
+        assert_eq!(fit.residuals.len(), 4);
+        assert!( approx_eq!(f64, fit.residuals[0], -fit.residuals[1], epsilon = 0.0001) );
+    }
+
+    #[test]
+    fn test_fit_plane_to_vertical_points() {
+        // Arrange
+        let points = [
+            Vector3::new(1.0, 0.0, 0.0),
+            Vector3::new(1.0, 5.0, 0.0),
+            Vector3::new(1.0, 0.0, 5.0),
+            Vector3::new(1.0, 5.0, 5.0)];
+
+        // Act
+        let fit = fit_plane(&points).unwrap();
+
+        // Assert
+        assert!( approx_eq!(f64, fit.plane.normal.x.abs(), 1.0, epsilon = 0.0001) );
+        assert!( approx_eq!(f64, fit.rms_error(), 0.0, epsilon = 0.0001) );
+    }
+
+    #[test]
+    fn test_fit_plane_to_collinear_points() {
+        // Arrange
+        let points = [Vector3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 1.0, 1.0), Vector3::new(2.0, 2.0, 2.0)];
+
+        // Act & Assert
+        assert!(fit_plane(&points).is_none());
+        assert!(fit_plane(&points[..2]).is_none());
+    }
+
+    #[test]
+    fn test_fit_line() {
+        // Arrange
+        let points = [
+            Vector3::new(0.0, 0.1, 0.0),
+            Vector3::new(1.0, -0.1, 0.0),
+            Vector3::new(2.0, -0.1, 0.0),
+            Vector3::new(3.0, 0.1, 0.0)];
+
+        // Act
+        let fit = fit_line(&points).unwrap();
+
+        // Assert
+        assert!( approx_eq!(f64, fit.line.direction.x.abs(), 1.0, epsilon = 0.0001) );
+        assert!( approx_eq!(f64, fit.line.point.x, 1.5, epsilon = 0.0001) );
+        assert!( approx_eq!(f64, fit.rms_error(), 0.1, epsilon = 0.0001) );
+        assert!(fit.residuals.iter().all(|residual| *residual >= 0.0));
+        assert!(fit_line(&[Vector3::new(1.0, 1.0, 1.0); 3]).is_none());
+    }
+
+    #[test]
+    fn test_fit_line_to_coincident_points() {
+        // Arrange
+        // 0.1, 0.7 and 0.3 can't be represented exactly, so the mean is not exactly equal to the points
+        let point = Vector3::new(0.1, 0.7, 0.3);
+
+        // Act & Assert
+        assert!(fit_line(&[point; 3]).is_none());
+        assert!(fit_line(&[point; 5]).is_none());
+        assert!(fit_line(&[Vector3::new(0.0, 0.0, 0.0); 4]).is_none());
+    }
+
+    #[test]
+    fn test_fit_circle_2d() {
+        // Arrange
+        let center = Vector2::new(100.0, -50.0);
+        let points: Vec<Vector2<f64>> = (0..8)
+            .map(|index| {
+                let angle = index as f64 * core::f64::consts::PI / 4.0;
+                center + Vector2::new(angle.cos(), angle.sin()).scalar_multiplication(3.0)
+            })
+            .collect();
+
+        // Act
+        let fit = fit_circle_2d(&points).unwrap();
+
+        // Assert
+        assert!( approx_eq!(f64, fit.center.x, 100.0, epsilon = 0.0001) );
+        assert!( approx_eq!(f64, fit.center.y, -50.0, epsilon = 0.0001) );
+        assert!( approx_eq!(f64, fit.radius, 3.0, epsilon = 0.0001) );
+        assert!( approx_eq!(f64, fit.rms_error(), 0.0, epsilon = 0.0001) );
+    }
+
+    #[test]
+    fn test_fit_circle_2d_to_collinear_points() {
+        // Arrange
+        let points = [Vector2::new(0.0, 0.0), Vector2::new(1.0, 1.0), Vector2::new(2.0, 2.0)];
+
+        // Act & Assert
+        assert!(fit_circle_2d(&points).is_none());
+    }
+}
@@ -0,0 +1,124 @@
+use core::ops::{Add, Sub, Mul, Div, Neg};
+
+use crate::sqrt_trait::Sqrt;
+use crate::math;
+
+// Geometry code (planes, bounding volumes, etc.) needs more than what the vectors require of "T".
+// It needs division, negation, comparisons and a couple of well-known constants.
+// Rather than repeating that long list of bounds everywhere, it is collected in this trait.
+pub trait Float: Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self> + Neg<Output = Self> + PartialOrd + Default + Copy + Sqrt {
+    fn zero() -> Self;
+    fn one() -> Self;
+    fn epsilon() -> Self;
+    // Used to express constants in generic code, for example "T::from_f64(0.5)"
+    fn from_f64(value: f64) -> Self;
+    fn abs(self) -> Self;
+    fn floor(self) -> Self;
+    fn ceil(self) -> Self;
+    fn round(self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn tan(self) -> Self;
+    // The angle of the point (x, y), with self as y, in the range -pi to pi
+    fn atan2(self, x: Self) -> Self;
+}
+
+impl Float for f32 {
+    fn zero() -> Self {
+        0.0
+    }
+
+    fn one() -> Self {
+        1.0
+    }
+
+    fn epsilon() -> Self {
+        f32::EPSILON
+    }
+
+    fn from_f64(value: f64) -> Self {
+        value as f32
+    }
+
+    fn abs(self) -> Self {
+        math::absf(self)
+    }
+
+    fn floor(self) -> Self {
+        math::floorf(self)
+    }
+
+    fn ceil(self) -> Self {
+        math::ceilf(self)
+    }
+
+    fn round(self) -> Self {
+        math::roundf(self)
+    }
+
+    fn sin(self) -> Self {
+        math::sinf(self)
+    }
+
+    fn cos(self) -> Self {
+        math::cosf(self)
+    }
+
+    fn tan(self) -> Self {
+        math::tanf(self)
+    }
+
+    fn atan2(self, x: Self) -> Self {
+        math::atan2f(self, x)
+    }
+}
+
+impl Float for f64 {
+    fn zero() -> Self {
+        0.0
+    }
+
+    fn one() -> Self {
+        1.0
+    }
+
+    fn epsilon() -> Self {
+        f64::EPSILON
+    }
+
+    fn from_f64(value: f64) -> Self {
+        value
+    }
+
+    fn abs(self) -> Self {
+        math::abs(self)
+    }
+
+    fn floor(self) -> Self {
+        math::floor(self)
+    }
+
+    fn ceil(self) -> Self {
+        math::ceil(self)
+    }
+
+    fn round(self) -> Self {
+        math::round(self)
+    }
+
+    fn sin(self) -> Self {
+        math::sin(self)
+    }
+
+    fn cos(self) -> Self {
+        math::cos(self)
+    }
+
+    fn tan(self) -> Self {
+        math::tan(self)
+    }
+
+    fn atan2(self, x: Self) -> Self {
+        math::atan2(self, x)
+    }
+}
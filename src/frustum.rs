@@ -0,0 +1,397 @@
+use core::fmt;
+
+use crate::vector3::Vector3;
+use crate::matrix4x4::Matrix4x4;
+use crate::plane::Plane;
+use crate::aabb::Aabb3;
+
+// The depth range of clip space differs between graphics APIs.
+// OpenGL maps the near and far planes to z = -1 and z = 1, while Direct3D, Vulkan and Metal map them to z = 0 and z = 1.
+// This only affects the near plane during extraction.
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ClipConvention {
+    NegativeOneToOne,
+    ZeroToOne
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Containment {
+    Outside,
+    Intersecting,
+    Inside
+}
+
+// Testing a volume against each of the six planes separately never culls something that is visible,
+// but large volumes near the edges of the frustum can be reported as intersecting while actually being outside.
+// The precise mode additionally tests the corners of the frustum against the volume's bounding box, which catches most of those cases.
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CullMode {
+    Conservative,
+    Precise
+}
+
+pub const LEFT: usize = 0;
+pub const RIGHT: usize = 1;
+pub const BOTTOM: usize = 2;
+pub const TOP: usize = 3;
+pub const NEAR: usize = 4;
+pub const FAR: usize = 5;
+
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(from = "SerializedFrustum", into = "SerializedFrustum"))]
+#[repr(C)]
+pub struct Frustum {
+    // All planes are normalized and have their normals pointing into the frustum.
+    pub planes: [Plane<f32>; 6],
+    pub cull_mode: CullMode,
+    // The corners are None if the frustum has no finite corners, for example when using an infinite far plane.
+    corners: Option<[Vector3<f32>; 8]>
+}
+
+// Only the planes and the cull mode are written when serializing. The corners are computed again when deserializing.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct SerializedFrustum {
+    planes: [Plane<f32>; 6],
+    cull_mode: CullMode
+}
+
+#[cfg(feature = "serde")]
+impl From<SerializedFrustum> for Frustum {
+    fn from(serialized: SerializedFrustum) -> Frustum {
+        Frustum {
+            planes: serialized.planes,
+            cull_mode: serialized.cull_mode,
+            corners: Frustum::compute_corners(&serialized.planes)
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<Frustum> for SerializedFrustum {
+    fn from(frustum: Frustum) -> SerializedFrustum {
+        SerializedFrustum {
+            planes: frustum.planes,
+            cull_mode: frustum.cull_mode
+        }
+    }
+}
+
+impl Frustum {
+    // Extracts the six planes from a (view-)projection matrix using the Gribb-Hartmann method.
+    // A point p is inside the clip volume when -w <= x <= w, -w <= y <= w and -w <= z <= w (or 0 <= z <= w),
+    // where (x, y, z, w) = M * p. Each of those inequalities is a plane, formed by adding or subtracting the rows of M.
+    // If only a projection matrix is given, the planes are in view space. With a view-projection matrix they are in world space.
+    pub fn from_matrix(matrix: &Matrix4x4, clip_convention: ClipConvention) -> Frustum {
+        let row = |r: usize| (Vector3::new(matrix[[r, 0]], matrix[[r, 1]], matrix[[r, 2]]), matrix[[r, 3]]);
+
+        let (row0, w0) = row(0);
+        let (row1, w1) = row(1);
+        let (row2, w2) = row(2);
+        let (row3, w3) = row(3);
+
+        let near = match clip_convention {
+            ClipConvention::NegativeOneToOne => Plane::new(row3 + row2, w3 + w2),
+            ClipConvention::ZeroToOne => Plane::new(row2, w2)
+        };
+
+        let planes = [
+            Plane::new(row3 + row0, w3 + w0).normalize(),
+            Plane::new(row3 - row0, w3 - w0).normalize(),
+            Plane::new(row3 + row1, w3 + w1).normalize(),
+            Plane::new(row3 - row1, w3 - w1).normalize(),
+            near.normalize(),
+            Plane::new(row3 - row2, w3 - w2).normalize()
+        ];
+
+        Frustum {
+            planes,
+            cull_mode: CullMode::Conservative,
+            corners: Frustum::compute_corners(&planes)
+        }
+    }
+
+    fn compute_corners(planes: &[Plane<f32>; 6]) -> Option<[Vector3<f32>; 8]> {
+        let corner = |x: usize, y: usize, z: usize| Plane::intersect_three(planes[x], planes[y], planes[z]);
+
+        Some([
+            corner(LEFT, BOTTOM, NEAR)?,
+            corner(RIGHT, BOTTOM, NEAR)?,
+            corner(LEFT, TOP, NEAR)?,
+            corner(RIGHT, TOP, NEAR)?,
+            corner(LEFT, BOTTOM, FAR)?,
+            corner(RIGHT, BOTTOM, FAR)?,
+            corner(LEFT, TOP, FAR)?,
+            corner(RIGHT, TOP, FAR)?
+        ])
+    }
+
+    // The eight corners of the frustum, near plane first, in the order:
+    // left-bottom, right-bottom, left-top, right-top.
+    pub fn corners(&self) -> Option<[Vector3<f32>; 8]> {
+        self.corners
+    }
+
+    pub fn contains_point(&self, point: Vector3<f32>) -> bool {
+        self.planes.iter().all(|plane| plane.signed_distance(point) >= 0.0)
+    }
+
+    pub fn classify_sphere(&self, center: Vector3<f32>, radius: f32) -> Containment {
+        let mut containment = Containment::Inside;
+
+        for plane in &self.planes {
+            let distance = plane.signed_distance(center);
+
+            if distance < -radius {
+                return Containment::Outside;
+            }
+
+            if distance < radius {
+                containment = Containment::Intersecting;
+            }
+        }
+
+        if containment == Containment::Intersecting && self.cull_mode == CullMode::Precise {
+            let extents = Vector3::new(radius, radius, radius);
+
+            if self.corners_outside_aabb(Aabb3::new(center - extents, center + extents)) {
+                return Containment::Outside;
+            }
+        }
+
+        containment
+    }
+
+    pub fn classify_aabb(&self, aabb: &Aabb3<f32>) -> Containment {
+        let center = aabb.center();
+        let half_extents = aabb.half_extents();
+
+        let mut containment = Containment::Inside;
+
+        for plane in &self.planes {
+            // The projected "radius" of the box onto the plane normal
+            let radius = half_extents.x * plane.normal.x.abs() + half_extents.y * plane.normal.y.abs() + half_extents.z * plane.normal.z.abs();
+            let distance = plane.signed_distance(center);
+
+            if distance + radius < 0.0 {
+                return Containment::Outside;
+            }
+
+            if distance - radius < 0.0 {
+                containment = Containment::Intersecting;
+            }
+        }
+
+        if containment == Containment::Intersecting && self.cull_mode == CullMode::Precise && self.corners_outside_aabb(*aabb) {
+            return Containment::Outside;
+        }
+
+        containment
+    }
+
+    pub fn is_sphere_visible(&self, center: Vector3<f32>, radius: f32) -> bool {
+        self.classify_sphere(center, radius) != Containment::Outside
+    }
+
+    pub fn is_aabb_visible(&self, aabb: &Aabb3<f32>) -> bool {
+        self.classify_aabb(aabb) != Containment::Outside
+    }
+
+    // Returns true if all corners of the frustum lie on the outer side of one of the box's faces.
+    fn corners_outside_aabb(&self, aabb: Aabb3<f32>) -> bool {
+        let corners = match self.corners {
+            Some(corners) => corners,
+            None => return false
+        };
+
+        corners.iter().all(|c| c.x > aabb.max.x) || corners.iter().all(|c| c.x < aabb.min.x) ||
+        corners.iter().all(|c| c.y > aabb.max.y) || corners.iter().all(|c| c.y < aabb.min.y) ||
+        corners.iter().all(|c| c.z > aabb.max.z) || corners.iter().all(|c| c.z < aabb.min.z)
+    }
+}
+
+// Prints one plane per line, each prefixed by its name.
+impl fmt::Display for Frustum {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let names = ["left", "right", "bottom", "top", "near", "far"];
+
+        for (index, (name, plane)) in names.iter().zip(self.planes.iter()).enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+
+            write!(f, "{}: ", name)?;
+            fmt::Display::fmt(plane, f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use float_cmp::approx_eq;
+
+    fn unit_cube_frustum() -> Frustum {
+        Frustum::from_matrix(&Matrix4x4::orthographic(-1.0, 1.0, -1.0, 1.0, -1.0, 1.0), ClipConvention::NegativeOneToOne)
+    }
+
+    // A perspective projection looking down the negative z axis, with a 90 degree vertical field of view,
+    // an aspect ratio of 1 and the near and far planes at 1 and 10.
+    fn perspective_frustum(clip_convention: ClipConvention) -> Frustum {
+        let (near, far) = (1.0, 10.0);
+
+        let projection = match clip_convention {
+            ClipConvention::NegativeOneToOne => Matrix4x4::new(
+                1.0, 0.0, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, -(far + near) / (far - near), -2.0 * far * near / (far - near),
+                0.0, 0.0, -1.0, 0.0),
+            ClipConvention::ZeroToOne => Matrix4x4::new(
+                1.0, 0.0, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, -far / (far - near), -far * near / (far - near),
+                0.0, 0.0, -1.0, 0.0)
+        };
+
+        Frustum::from_matrix(&projection, clip_convention)
+    }
+
+    #[test]
+    fn test_plane_extraction() {
+        // Act
+        let frustum = unit_cube_frustum();
+
+        // Assert
+        let left = frustum.planes[LEFT];
+        assert!( approx_eq!(f32, left.normal.x, 1.0, epsilon = 0.0001) );
+        assert!( approx_eq!(f32, left.distance, 1.0, epsilon = 0.0001) );
+
+        let far = frustum.planes[FAR];
+        assert!( approx_eq!(f32, far.normal.z, -1.0, epsilon = 0.0001) );
+        assert!( approx_eq!(f32, far.distance, 1.0, epsilon = 0.0001) );
+    }
+
+    #[test]
+    fn test_near_plane_per_clip_convention() {
+        // Act
+        let opengl_frustum = perspective_frustum(ClipConvention::NegativeOneToOne);
+        let direct3d_frustum = perspective_frustum(ClipConvention::ZeroToOne);
+
+        // Assert
+        // Both conventions should place the near plane at z = -1 in view space
+        for frustum in &[opengl_frustum, direct3d_frustum] {
+            let near = frustum.planes[NEAR];
+            assert!( approx_eq!(f32, near.normal.z, -1.0, epsilon = 0.0001) );
+            assert!( approx_eq!(f32, near.distance, -1.0, epsilon = 0.0001) );
+        }
+    }
+
+    #[test]
+    fn test_contains_point() {
+        // Arrange
+        let frustum = perspective_frustum(ClipConvention::NegativeOneToOne);
+
+        // Act & Assert
+        assert!(frustum.contains_point(Vector3::new(0.0, 0.0, -5.0)));
+        assert!(frustum.contains_point(Vector3::new(4.0, -4.0, -5.0)));
+        assert!(!frustum.contains_point(Vector3::new(6.0, 0.0, -5.0)));
+        assert!(!frustum.contains_point(Vector3::new(0.0, 0.0, -0.5)));
+        assert!(!frustum.contains_point(Vector3::new(0.0, 0.0, -11.0)));
+    }
+
+    #[test]
+    fn test_classify_sphere() {
+        // Arrange
+        let frustum = perspective_frustum(ClipConvention::ZeroToOne);
+
+        // Act & Assert
+        assert_eq!(frustum.classify_sphere(Vector3::new(0.0, 0.0, -5.0), 1.0), Containment::Inside);
+        assert_eq!(frustum.classify_sphere(Vector3::new(0.0, 0.0, -10.0), 1.0), Containment::Intersecting);
+        assert_eq!(frustum.classify_sphere(Vector3::new(0.0, 0.0, 5.0), 1.0), Containment::Outside);
+    }
+
+    #[test]
+    fn test_classify_aabb() {
+        // Arrange
+        let frustum = unit_cube_frustum();
+
+        let inside = Aabb3::new(Vector3::new(-0.5, -0.5, -0.5), Vector3::new(0.5, 0.5, 0.5));
+        let intersecting = Aabb3::new(Vector3::new(0.5, 0.5, 0.5), Vector3::new(1.5, 1.5, 1.5));
+        let outside = Aabb3::new(Vector3::new(2.0, 2.0, 2.0), Vector3::new(3.0, 3.0, 3.0));
+
+        // Act & Assert
+        assert_eq!(frustum.classify_aabb(&inside), Containment::Inside);
+        assert_eq!(frustum.classify_aabb(&intersecting), Containment::Intersecting);
+        assert_eq!(frustum.classify_aabb(&outside), Containment::Outside);
+    }
+
+    #[test]
+    fn test_precise_cull_mode() {
+        // Arrange
+        // This box lies beyond the far corner of the frustum, outside of it, but it straddles both the right and the far planes,
+        // so neither plane alone is able to reject it.
+        let mut frustum = perspective_frustum(ClipConvention::NegativeOneToOne);
+        let aabb = Aabb3::new(Vector3::new(10.5, -1.0, -30.0), Vector3::new(30.0, 1.0, -9.5));
+
+        // Act
+        let conservative_result = frustum.classify_aabb(&aabb);
+        frustum.cull_mode = CullMode::Precise;
+        let precise_result = frustum.classify_aabb(&aabb);
+
+        // Assert
+        assert_eq!(conservative_result, Containment::Intersecting);
+        assert_eq!(precise_result, Containment::Outside);
+    }
+
+    #[test]
+    fn test_display() {
+        // Arrange
+        let frustum = unit_cube_frustum();
+
+        // Act
+        let display = format!("{:.1}", frustum);
+
+        // Assert
+        assert_eq!(display.lines().count(), 6);
+        assert_eq!(display.lines().next(), Some("left: normal (1.0, 0.0, 0.0), distance 1.0"));
+    }
+
+    #[test]
+    fn test_corners() {
+        // Arrange
+        let frustum = perspective_frustum(ClipConvention::NegativeOneToOne);
+
+        // Act
+        let corners = frustum.corners().unwrap();
+
+        // Assert
+        assert!( approx_eq!(f32, corners[0].x, -1.0, epsilon = 0.0001) );
+        assert!( approx_eq!(f32, corners[0].z, -1.0, epsilon = 0.0001) );
+        assert!( approx_eq!(f32, corners[7].x, 10.0, epsilon = 0.001) );
+        assert!( approx_eq!(f32, corners[7].y, 10.0, epsilon = 0.001) );
+        assert!( approx_eq!(f32, corners[7].z, -10.0, epsilon = 0.001) );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        // Arrange
+        let mut frustum = perspective_frustum(ClipConvention::ZeroToOne);
+        frustum.cull_mode = CullMode::Precise;
+
+        // Act
+        let json = serde_json::to_string(&frustum).unwrap();
+        let deserialized_frustum: Frustum = serde_json::from_str(&json).unwrap();
+
+        // Assert
+        assert!(!json.contains("corners"));
+        assert_eq!(deserialized_frustum.cull_mode, CullMode::Precise);
+        assert_eq!(deserialized_frustum.planes[NEAR].distance, frustum.planes[NEAR].distance);
+        assert_eq!(deserialized_frustum.corners().unwrap()[7].z, frustum.corners().unwrap()[7].z);
+    }
+}
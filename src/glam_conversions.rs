@@ -0,0 +1,89 @@
+use crate::vector2::Vector2;
+use crate::vector3::Vector3;
+use crate::vector4::Vector4;
+use crate::matrix4x4::Matrix4x4;
+
+// The conversions are between the f32 types of this crate and glam's Vec2, Vec3, Vec4 and Mat4.
+
+impl From<glam::Vec2> for Vector2<f32> {
+    fn from(vector: glam::Vec2) -> Vector2<f32> {
+        Vector2::new(vector.x, vector.y)
+    }
+}
+
+impl From<Vector2<f32>> for glam::Vec2 {
+    fn from(vector: Vector2<f32>) -> glam::Vec2 {
+        glam::Vec2::new(vector.x, vector.y)
+    }
+}
+
+impl From<glam::Vec3> for Vector3<f32> {
+    fn from(vector: glam::Vec3) -> Vector3<f32> {
+        Vector3::new(vector.x, vector.y, vector.z)
+    }
+}
+
+impl From<Vector3<f32>> for glam::Vec3 {
+    fn from(vector: Vector3<f32>) -> glam::Vec3 {
+        glam::Vec3::new(vector.x, vector.y, vector.z)
+    }
+}
+
+impl From<glam::Vec4> for Vector4<f32> {
+    fn from(vector: glam::Vec4) -> Vector4<f32> {
+        Vector4::new(vector.x, vector.y, vector.z, vector.w)
+    }
+}
+
+impl From<Vector4<f32>> for glam::Vec4 {
+    fn from(vector: Vector4<f32>) -> glam::Vec4 {
+        glam::Vec4::new(vector.x, vector.y, vector.z, vector.w)
+    }
+}
+
+// glam::Mat4 is column-major as well, so the elements can be passed on as a column-major array
+impl From<glam::Mat4> for Matrix4x4 {
+    fn from(matrix: glam::Mat4) -> Matrix4x4 {
+        Matrix4x4::from_cols_array(matrix.to_cols_array())
+    }
+}
+
+impl From<Matrix4x4> for glam::Mat4 {
+    fn from(matrix: Matrix4x4) -> glam::Mat4 {
+        glam::Mat4::from_cols_array(&matrix.to_cols_array())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vector_round_trip() {
+        // Arrange
+        let vector_a = Vector3::<f32>::new(1.0, 2.0, 3.0);
+
+        // Act
+        let glam_vector: glam::Vec3 = vector_a.into();
+        let converted_vector: Vector3<f32> = glam_vector.into();
+
+        // Assert
+        assert_eq!(glam_vector, glam::Vec3::new(1.0, 2.0, 3.0));
+        assert_eq!(converted_vector, vector_a);
+    }
+
+    #[test]
+    fn test_matrix_round_trip() {
+        // Arrange
+        let matrix_a = Matrix4x4::identity().translate(Vector2::new(5.0, 7.0));
+
+        // Act
+        let glam_matrix: glam::Mat4 = matrix_a.into();
+        let converted_matrix: Matrix4x4 = glam_matrix.into();
+
+        // Assert
+        assert_eq!(glam_matrix, glam::Mat4::from_translation(glam::Vec3::new(5.0, 7.0, 0.0)));
+        assert_eq!(glam_matrix.transform_point3(glam::Vec3::ZERO), glam::Vec3::new(5.0, 7.0, 0.0));
+        assert!(converted_matrix == matrix_a);
+    }
+}
@@ -0,0 +1,102 @@
+// The overflow-aware arithmetic of Rust's integer types are inherent methods, not trait methods,
+// so generic code like the vectors can't call them directly. This trait exposes them for all the primitive integer types.
+pub trait Integer: Copy + Sized {
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+    fn saturating_add(self, other: Self) -> Self;
+    fn saturating_sub(self, other: Self) -> Self;
+    fn saturating_mul(self, other: Self) -> Self;
+    fn wrapping_add(self, other: Self) -> Self;
+    fn wrapping_sub(self, other: Self) -> Self;
+    fn wrapping_mul(self, other: Self) -> Self;
+}
+
+macro_rules! impl_integer {
+    ($( $integer:ty ),+) => {
+        $(
+            impl Integer for $integer {
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$integer>::checked_add(self, other)
+                }
+
+                fn checked_sub(self, other: Self) -> Option<Self> {
+                    <$integer>::checked_sub(self, other)
+                }
+
+                fn checked_mul(self, other: Self) -> Option<Self> {
+                    <$integer>::checked_mul(self, other)
+                }
+
+                fn saturating_add(self, other: Self) -> Self {
+                    <$integer>::saturating_add(self, other)
+                }
+
+                fn saturating_sub(self, other: Self) -> Self {
+                    <$integer>::saturating_sub(self, other)
+                }
+
+                fn saturating_mul(self, other: Self) -> Self {
+                    <$integer>::saturating_mul(self, other)
+                }
+
+                fn wrapping_add(self, other: Self) -> Self {
+                    <$integer>::wrapping_add(self, other)
+                }
+
+                fn wrapping_sub(self, other: Self) -> Self {
+                    <$integer>::wrapping_sub(self, other)
+                }
+
+                fn wrapping_mul(self, other: Self) -> Self {
+                    <$integer>::wrapping_mul(self, other)
+                }
+            }
+        )+
+    };
+}
+
+impl_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+// Converts a float to an integer the same way "as" does, by truncating towards zero,
+// except that NaN, infinity and values outside the range of the integer type give None instead of a saturated value.
+pub(crate) fn f32_to_i32(value: f32) -> Option<i32> {
+    // i32::MIN is exactly representable as an f32, and its negation is the first value outside of the range
+    if value.is_finite() && value >= i32::MIN as f32 && value < -(i32::MIN as f32) {
+        Some(value as i32)
+    } else {
+        None
+    }
+}
+
+pub(crate) fn f32_to_u32(value: f32) -> Option<u32> {
+    if value.is_finite() && value > -1.0 && value < 4_294_967_296.0 {
+        Some(value as u32)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checked_arithmetic() {
+        // Act & Assert
+        assert_eq!(Integer::checked_add(i32::MAX, 1), None);
+        assert_eq!(Integer::checked_sub(0u8, 1), None);
+        assert_eq!(Integer::checked_mul(4i16, 5), Some(20));
+    }
+
+    #[test]
+    fn test_float_to_integer() {
+        // Act & Assert
+        assert_eq!(f32_to_i32(-3.7), Some(-3));
+        assert_eq!(f32_to_i32(f32::NAN), None);
+        assert_eq!(f32_to_i32(3.0e9), None);
+        assert_eq!(f32_to_u32(-0.5), Some(0));
+        assert_eq!(f32_to_u32(-1.0), None);
+        assert_eq!(f32_to_u32(3.0e9), Some(3_000_000_000));
+    }
+}
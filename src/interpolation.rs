@@ -0,0 +1,144 @@
+use crate::float_trait::Float;
+
+// Linear interpolation between two values. A "t" of 0 gives "self", a "t" of 1 gives "other", and values in between blend the two.
+// "t" is not clamped, so values outside of 0 to 1 extrapolate.
+// "S" is the type of "t", which is the component type for vectors and matrices.
+pub trait Lerp<S> {
+    fn lerp(self, other: Self, t: S) -> Self;
+}
+
+impl Lerp<f32> for f32 {
+    fn lerp(self, other: Self, t: f32) -> Self {
+        self + (other - self) * t
+    }
+}
+
+impl Lerp<f64> for f64 {
+    fn lerp(self, other: Self, t: f64) -> Self {
+        self + (other - self) * t
+    }
+}
+
+// The inverse of lerp. Finds the "t" at which "value" lies between "a" and "b".
+// When "a" and "b" are equal the range is a single point, and the result is a step like GLSL's step: 0 below it, and 1 at or above it.
+// This is what makes "smoothstep(edge, edge, x)" a hard step instead of dividing by zero.
+pub fn inverse_lerp<T: Float>(a: T, b: T, value: T) -> T {
+    if a == b {
+        return if value < a { T::zero() } else { T::one() };
+    }
+
+    (value - a) / (b - a)
+}
+
+// Maps "value" from the range [from_min, from_max] to the range [to_min, to_max].
+pub fn remap<T: Float>(value: T, from_min: T, from_max: T, to_min: T, to_max: T) -> T {
+    let t = inverse_lerp(from_min, from_max, value);
+
+    to_min + (to_max - to_min) * t
+}
+
+pub fn clamp<T: PartialOrd>(value: T, min: T, max: T) -> T {
+    if value < min {
+        min
+    } else if value > max {
+        max
+    } else {
+        value
+    }
+}
+
+// Smooth Hermite interpolation between 0 and 1 as "x" goes from "edge0" to "edge1", the same as GLSL's smoothstep.
+// The result has a zero first derivative at both edges.
+pub fn smoothstep<T: Float>(edge0: T, edge1: T, x: T) -> T {
+    let t = clamp(inverse_lerp(edge0, edge1, x), T::zero(), T::one());
+
+    t * t * (T::from_f64(3.0) - T::from_f64(2.0) * t)
+}
+
+// Ken Perlin's variation of smoothstep, which also has a zero second derivative at both edges.
+pub fn smootherstep<T: Float>(edge0: T, edge1: T, x: T) -> T {
+    let t = clamp(inverse_lerp(edge0, edge1, x), T::zero(), T::one());
+
+    t * t * t * (t * (t * T::from_f64(6.0) - T::from_f64(15.0)) + T::from_f64(10.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vector3::Vector3;
+    use float_cmp::approx_eq;
+
+    #[test]
+    fn test_lerp_scalar() {
+        // Act
+        let halfway = 2.0_f32.lerp(4.0, 0.5);
+        let extrapolated = 2.0_f64.lerp(4.0, 2.0);
+
+        // Assert
+        assert_eq!(halfway, 3.0);
+        assert_eq!(extrapolated, 6.0);
+    }
+
+    #[test]
+    fn test_lerp_vector() {
+        // Arrange
+        let vector_a = Vector3::<f64>::new(0.0, 2.0, 4.0);
+        let vector_b = Vector3::<f64>::new(4.0, 2.0, 0.0);
+
+        // Act
+        let interpolated_vector = vector_a.lerp(vector_b, 0.25);
+
+        // Assert
+        assert_eq!(interpolated_vector.x, 1.0);
+        assert_eq!(interpolated_vector.y, 2.0);
+        assert_eq!(interpolated_vector.z, 3.0);
+    }
+
+    #[test]
+    fn test_inverse_lerp_and_remap() {
+        // Act
+        let t = inverse_lerp(10.0, 20.0, 15.0);
+        let remapped_value = remap(15.0, 10.0, 20.0, 0.0, 100.0);
+
+        // Assert
+        assert_eq!(t, 0.5);
+        assert_eq!(remapped_value, 50.0);
+    }
+
+    #[test]
+    fn test_clamp() {
+        // Act & Assert
+        assert_eq!(clamp(5, 0, 10), 5);
+        assert_eq!(clamp(-5, 0, 10), 0);
+        assert_eq!(clamp(15.0, 0.0, 10.0), 10.0);
+    }
+
+    #[test]
+    fn test_smoothstep() {
+        // Act & Assert
+        assert_eq!(smoothstep(0.0, 1.0, -1.0), 0.0);
+        assert_eq!(smoothstep(0.0, 1.0, 0.5), 0.5);
+        assert_eq!(smoothstep(0.0, 1.0, 2.0), 1.0);
+        assert!( approx_eq!(f64, smoothstep(0.0, 1.0, 0.25), 0.15625, epsilon = 0.00001) );
+    }
+
+    #[test]
+    fn test_empty_range_is_a_step() {
+        // Act & Assert
+        assert_eq!(inverse_lerp(2.0, 2.0, 1.0), 0.0);
+        assert_eq!(inverse_lerp(2.0, 2.0, 2.0), 1.0);
+        assert_eq!(remap(3.0, 2.0, 2.0, 10.0, 20.0), 20.0);
+        assert_eq!(smoothstep(0.5, 0.5, 0.4), 0.0);
+        assert_eq!(smoothstep(0.5, 0.5, 0.5), 1.0);
+        assert_eq!(smootherstep(0.5, 0.5, 0.6), 1.0);
+    }
+
+    #[test]
+    fn test_smootherstep() {
+        // Act & Assert
+        assert_eq!(smootherstep(0.0, 1.0, -1.0), 0.0);
+        assert_eq!(smootherstep(0.0, 1.0, 0.5), 0.5);
+        assert_eq!(smootherstep(0.0, 1.0, 2.0), 1.0);
+        assert!( approx_eq!(f64, smootherstep(0.0, 1.0, 0.25), 0.103515625, epsilon = 0.00001) );
+    }
+}
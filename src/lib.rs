@@ -1,4 +1,64 @@
+// Without the "std" feature the crate only depends on core, so it can be used on embedded targets and in wasm.
+// Tests always link std, since the test harness needs it.
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(all(not(feature = "std"), not(feature = "libm")))]
+compile_error!("Without the \"std\" feature, the \"libm\" feature is required to provide sqrt and the trigonometric functions.");
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 pub mod vector2;
 pub mod vector3;
+pub mod vector4;
+pub mod matrix;
 pub mod matrix4x4;
-pub mod sqrt_trait;
\ No newline at end of file
+pub mod matrix3x3;
+pub mod lu;
+pub mod eigen;
+pub mod svd;
+pub mod matrix_exp;
+pub mod polar;
+pub mod rigid_transform;
+pub mod affine;
+pub mod quaternion;
+pub mod transform;
+pub mod rotation2;
+pub mod transform2;
+pub mod sqrt_trait;
+pub mod float_trait;
+mod math;
+pub mod integer_trait;
+pub mod plane;
+pub mod aabb;
+pub mod frustum;
+pub mod ray;
+pub mod line;
+pub mod sphere;
+pub mod obb;
+pub mod viewport;
+pub mod interpolation;
+pub mod swizzle;
+pub mod parse;
+#[cfg(feature = "alloc")]
+pub mod uniform_layout;
+#[cfg(feature = "alloc")]
+pub mod dmatrix;
+#[cfg(feature = "alloc")]
+pub mod qr;
+#[cfg(feature = "alloc")]
+pub mod fitting;
+#[cfg(feature = "alloc")]
+pub mod matrix_stack;
+#[cfg(feature = "serde")]
+pub mod serde_support;
+#[cfg(feature = "bytemuck")]
+pub mod bytemuck_support;
+#[cfg(feature = "mint")]
+mod mint_conversions;
+#[cfg(feature = "glam")]
+mod glam_conversions;
+#[cfg(feature = "nalgebra")]
+mod nalgebra_conversions;
+#[cfg(feature = "cgmath")]
+mod cgmath_conversions;
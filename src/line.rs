@@ -0,0 +1,63 @@
+use core::fmt;
+
+use crate::vector3::Vector3;
+use crate::float_trait::Float;
+
+// An infinite line through "point", extending in both the positive and negative "direction".
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Line<T> where T: Float {
+    pub point: Vector3<T>,
+    pub direction: Vector3<T>
+}
+
+impl<T: Float> Line<T> {
+    pub fn new(point: Vector3<T>, direction: Vector3<T>) -> Line<T> {
+        Line {
+            point,
+            direction
+        }
+    }
+
+    pub fn from_points(a: Vector3<T>, b: Vector3<T>) -> Line<T> {
+        Line {
+            point: a,
+            direction: b - a
+        }
+    }
+
+    pub fn point_at(self, parameter: T) -> Vector3<T> {
+        self.point + self.direction.scalar_multiplication(parameter)
+    }
+}
+
+impl<T: Float + fmt::Display> fmt::Display for Line<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "point ")?;
+        fmt::Display::fmt(&self.point, f)?;
+        write!(f, ", direction ")?;
+        fmt::Display::fmt(&self.direction, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_points() {
+        // Arrange
+        let point_a = Vector3::<f64>::new(1.0, 1.0, 1.0);
+        let point_b = Vector3::<f64>::new(3.0, 1.0, 1.0);
+
+        // Act
+        let line = Line::from_points(point_a, point_b);
+
+        // Assert
+        let point = line.point_at(-1.0);
+        assert_eq!(point.x, -1.0);
+        assert_eq!(point.y, 1.0);
+        assert_eq!(point.z, 1.0);
+    }
+}
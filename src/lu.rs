@@ -0,0 +1,474 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::float_trait::Float;
+use crate::matrix::{Matrix, Vector};
+#[cfg(feature = "alloc")]
+use crate::dmatrix::{DMatrix, DVector};
+
+// LU decomposition with partial pivoting: the rows of a square matrix A are reordered by a permutation P, so that PA = LU,
+// where L is lower triangular with ones on its diagonal, and U is upper triangular.
+// Once a matrix is decomposed, solving Ax = b is only a forward and a backward substitution, so the decomposition can be reused for many right-hand sides.
+// The determinant is the product of the diagonal of U, with its sign flipped once for every row swap.
+//
+// The algorithm is written once over a column-major slice, and shared by the fixed-size Matrix and the dynamically sized DMatrix.
+
+// Factors the n x n matrix stored in column-major order in "elements" in place. Afterwards, U is stored on and above the diagonal,
+// and L below it, leaving out its diagonal of ones. Returns the sign of the permutation, and whether the matrix is singular.
+// "column_scales" is scratch space for n values.
+//
+// In every step, the row with the largest element in the current column is swapped up to become the pivot row.
+// Dividing by the largest available element, rather than by whatever happens to be on the diagonal, keeps the rounding errors small.
+// A pivot that is within rounding error of zero means that the matrix is singular. The rounding error is measured against the largest element
+// of the pivot's column in the original matrix, rather than the largest element of the whole matrix, so that one large column,
+// such as the translation of a small object far from the origin, doesn't make the other columns look singular.
+fn decompose<T: Float>(elements: &mut [T], n: usize, permutation: &mut [usize], column_scales: &mut [T]) -> (T, bool) {
+    let at = |row: usize, column: usize| column * n + row;
+
+    let infinity = T::from_f64(f64::INFINITY);
+    let size = T::from_f64(n as f64);
+    let mut sign = T::one();
+    let mut singular = false;
+
+    for (index, row) in permutation.iter_mut().enumerate() {
+        *row = index;
+    }
+
+    for (column, scale) in column_scales.iter_mut().enumerate() {
+        *scale = (0..n).fold(T::zero(), |largest, row| if elements[at(row, column)].abs() > largest { elements[at(row, column)].abs() } else { largest });
+    }
+
+    for k in 0..n {
+        let mut pivot_row = k;
+
+        for row in k + 1..n {
+            if elements[at(row, k)].abs() > elements[at(pivot_row, k)].abs() {
+                pivot_row = row;
+            }
+        }
+
+        if pivot_row != k {
+            for column in 0..n {
+                elements.swap(at(k, column), at(pivot_row, column));
+            }
+
+            permutation.swap(k, pivot_row);
+            sign = -sign;
+        }
+
+        // Written this way around so that a NaN pivot also counts as singular
+        let pivot = elements[at(k, k)];
+        let tolerance = size * T::epsilon() * column_scales[k];
+        let is_pivot_usable = pivot.abs() > tolerance && pivot.abs() < infinity;
+
+        if !is_pivot_usable {
+            singular = true;
+            continue;
+        }
+
+        for row in k + 1..n {
+            let factor = elements[at(row, k)] / pivot;
+            elements[at(row, k)] = factor;
+
+            for column in k + 1..n {
+                elements[at(row, column)] = elements[at(row, column)] - factor * elements[at(k, column)];
+            }
+        }
+    }
+
+    (sign, singular)
+}
+
+// Solves LUx = Pb, with "lu" as written by decompose
+fn substitute<T: Float>(lu: &[T], n: usize, permutation: &[usize], b: &[T], x: &mut [T]) {
+    let at = |row: usize, column: usize| column * n + row;
+
+    for (element, row) in x.iter_mut().zip(permutation) {
+        *element = b[*row];
+    }
+
+    // Forward substitution with L, whose diagonal is all ones
+    for row in 0..n {
+        for k in 0..row {
+            x[row] = x[row] - lu[at(row, k)] * x[k];
+        }
+    }
+
+    // Backward substitution with U
+    for row in (0..n).rev() {
+        for k in row + 1..n {
+            x[row] = x[row] - lu[at(row, k)] * x[k];
+        }
+
+        x[row] = x[row] / lu[at(row, row)];
+    }
+}
+
+fn determinant<T: Float>(lu: &[T], n: usize, sign: T) -> T {
+    (0..n).fold(sign, |product, k| product * lu[k * n + k])
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct Lu<T, const N: usize> where T: Float {
+    lu: Matrix<T, N, N>,
+    permutation: [usize; N],
+    sign: T,
+    singular: bool
+}
+
+impl<T: Float, const N: usize> Lu<T, N> {
+    // Decomposing never fails. A singular matrix can still be decomposed, it is only solving and inverting that are not possible.
+    pub fn new(matrix: &Matrix<T, N, N>) -> Lu<T, N> {
+        let mut lu = *matrix;
+        let mut permutation = [0; N];
+        let mut column_scales = [T::zero(); N];
+        let (sign, singular) = decompose(lu.columns.as_flattened_mut(), N, &mut permutation, &mut column_scales);
+
+        Lu {
+            lu,
+            permutation,
+            sign,
+            singular
+        }
+    }
+
+    pub fn is_singular(&self) -> bool {
+        self.singular
+    }
+
+    // Row i of PA is row permutation()[i] of A
+    pub fn permutation(&self) -> [usize; N] {
+        self.permutation
+    }
+
+    pub fn lower(&self) -> Matrix<T, N, N> {
+        Matrix::from_fn(|row, column| {
+            if row == column {
+                T::one()
+            } else if row > column {
+                self.lu[[row, column]]
+            } else {
+                T::zero()
+            }
+        })
+    }
+
+    pub fn upper(&self) -> Matrix<T, N, N> {
+        Matrix::from_fn(|row, column| if row <= column { self.lu[[row, column]] } else { T::zero() })
+    }
+
+    pub fn determinant(&self) -> T {
+        determinant(self.lu.columns.as_flattened(), N, self.sign)
+    }
+
+    // Finds x such that Ax = b. Returns None if the matrix is singular, in which case there is either no solution or infinitely many.
+    pub fn solve(&self, b: Vector<T, N>) -> Option<Vector<T, N>> {
+        if self.singular {
+            return None;
+        }
+
+        let mut x = [T::zero(); N];
+        substitute(self.lu.columns.as_flattened(), N, &self.permutation, &b.to_array(), &mut x);
+
+        Some(Vector::from_array(x))
+    }
+
+    // Solves for every column of the identity matrix, which gives the columns of the inverse
+    pub fn inverse(&self) -> Option<Matrix<T, N, N>> {
+        let identity = Matrix::<T, N, N>::identity();
+        let mut inverse = identity;
+
+        for column in 0..N {
+            inverse.columns[column] = self.solve(identity.column(column))?.to_array();
+        }
+
+        Some(inverse)
+    }
+}
+
+impl<T: Float, const N: usize> Matrix<T, N, N> {
+    pub fn lu(&self) -> Lu<T, N> {
+        Lu::new(self)
+    }
+
+    pub fn determinant(&self) -> T {
+        self.lu().determinant()
+    }
+
+    // Finds x such that Ax = b, or returns None if the matrix is singular.
+    // To solve for several right-hand sides with the same matrix, decompose it once with lu() and call solve on that instead.
+    pub fn solve(&self, b: Vector<T, N>) -> Option<Vector<T, N>> {
+        self.lu().solve(b)
+    }
+
+    // Returns None if the matrix is singular, meaning that it has no inverse
+    pub fn inverse(&self) -> Option<Matrix<T, N, N>> {
+        self.lu().inverse()
+    }
+}
+
+// The LU decomposition of a DMatrix. It works the same as Lu, except that the sizes are checked at run-time.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug)]
+pub struct DLu<T> where T: Float {
+    lu: DMatrix<T>,
+    permutation: Vec<usize>,
+    sign: T,
+    singular: bool
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Float> DLu<T> {
+    // Panics if the matrix is not square, since only square matrices have an LU decomposition of this form
+    pub fn new(matrix: &DMatrix<T>) -> DLu<T> {
+        if !matrix.is_square() {
+            panic!("Only square matrices can be LU decomposed, but the matrix is {}x{}!", matrix.rows(), matrix.columns());
+        }
+
+        let n = matrix.rows();
+        let mut lu = matrix.clone();
+        let mut permutation = alloc::vec![0; n];
+        let mut column_scales = alloc::vec![T::zero(); n];
+        let (sign, singular) = decompose(lu.as_mut_slice(), n, &mut permutation, &mut column_scales);
+
+        DLu {
+            lu,
+            permutation,
+            sign,
+            singular
+        }
+    }
+
+    pub fn is_singular(&self) -> bool {
+        self.singular
+    }
+
+    pub fn permutation(&self) -> &[usize] {
+        &self.permutation
+    }
+
+    pub fn determinant(&self) -> T {
+        determinant(self.lu.as_slice(), self.lu.rows(), self.sign)
+    }
+
+    // Panics if the length of "b" does not match the size of the matrix
+    pub fn solve(&self, b: &DVector<T>) -> Option<DVector<T>> {
+        let n = self.lu.rows();
+
+        if b.len() != n {
+            panic!("Cannot solve a {}x{} system with a right-hand side of length {}!", n, n, b.len());
+        }
+
+        if self.singular {
+            return None;
+        }
+
+        let mut x = DVector::zeros(n);
+        substitute(self.lu.as_slice(), n, &self.permutation, b.as_slice(), x.as_mut_slice());
+
+        Some(x)
+    }
+
+    pub fn inverse(&self) -> Option<DMatrix<T>> {
+        let n = self.lu.rows();
+
+        if self.singular {
+            return None;
+        }
+
+        let identity = DMatrix::<T>::identity(n);
+        let mut inverse = DMatrix::zeros(n, n);
+
+        for column in 0..n {
+            let start = column * n;
+            substitute(self.lu.as_slice(), n, &self.permutation, identity.column(column), &mut inverse.as_mut_slice()[start..start + n]);
+        }
+
+        Some(inverse)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Float> DMatrix<T> {
+    pub fn lu(&self) -> DLu<T> {
+        DLu::new(self)
+    }
+
+    pub fn determinant(&self) -> T {
+        self.lu().determinant()
+    }
+
+    pub fn solve(&self, b: &DVector<T>) -> Option<DVector<T>> {
+        self.lu().solve(b)
+    }
+
+    pub fn inverse(&self) -> Option<DMatrix<T>> {
+        self.lu().inverse()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix3x3::Matrix3x3;
+    use crate::matrix4x4::Matrix4x4;
+    use crate::vector2::Vector2;
+    use crate::vector3::Vector3;
+    use crate::plane::Plane;
+    use float_cmp::approx_eq;
+
+    #[test]
+    fn test_decomposition_reproduces_matrix() {
+        // Arrange
+        let matrix = Matrix3x3::new(
+            1.0, 2.0, 3.0,
+            4.0, 5.0, 6.0,
+            7.0, 8.0, 10.0);
+
+        // Act
+        let lu = matrix.lu();
+        let permutation = lu.permutation();
+        let permuted_matrix = Matrix3x3::from_fn(|row, column| matrix[[permutation[row], column]]);
+        let product = lu.lower().mul(lu.upper());
+
+        // Assert
+        assert_eq!(permutation, [2, 0, 1]);
+
+        for row in 0..3 {
+            for column in 0..3 {
+                assert!( approx_eq!(f32, product[[row, column]], permuted_matrix[[row, column]], epsilon = 0.0001) );
+            }
+        }
+    }
+
+    #[test]
+    fn test_solve_and_determinant() {
+        // Arrange
+        let matrix = Matrix::<f64, 3, 3>::from_rows([
+            [2.0, 1.0, -1.0],
+            [-3.0, -1.0, 2.0],
+            [-2.0, 1.0, 2.0]]);
+
+        // Act
+        let solution = matrix.solve(Vector::from_array([8.0, -11.0, -3.0])).unwrap();
+
+        // Assert
+        assert!( approx_eq!(f64, solution[[0, 0]], 2.0, epsilon = 0.0001) );
+        assert!( approx_eq!(f64, solution[[1, 0]], 3.0, epsilon = 0.0001) );
+        assert!( approx_eq!(f64, solution[[2, 0]], -1.0, epsilon = 0.0001) );
+        assert!( approx_eq!(f64, matrix.determinant(), -1.0, epsilon = 0.0001) );
+    }
+
+    #[test]
+    fn test_pivoting_handles_zero_on_diagonal() {
+        // Arrange
+        let matrix = Matrix::<f32, 2, 2>::from_rows([[0.0, 1.0], [1.0, 0.0]]);
+
+        // Act
+        let solution = matrix.solve(Vector::from_array([2.0, 3.0])).unwrap();
+
+        // Assert
+        assert_eq!(solution.to_array(), [3.0, 2.0]);
+        assert_eq!(matrix.determinant(), -1.0);
+    }
+
+    #[test]
+    fn test_singular_matrix() {
+        // Arrange
+        let matrix = Matrix3x3::new(
+            1.0, 2.0, 3.0,
+            2.0, 4.0, 6.0,
+            0.0, 1.0, 1.0);
+
+        // Act
+        let lu = matrix.lu();
+
+        // Assert
+        assert!(lu.is_singular());
+        assert!(lu.solve(Vector::from_array([1.0, 2.0, 3.0])).is_none());
+        assert!(lu.inverse().is_none());
+        assert!( approx_eq!(f32, lu.determinant(), 0.0, epsilon = 0.0001) );
+    }
+
+    #[test]
+    fn test_numerically_singular_matrix() {
+        // Arrange
+        // The rows are evenly spaced, so the matrix is singular, but rounding leaves a last pivot of about 1e-16 instead of 0
+        let matrix = Matrix::<f64, 3, 3>::from_rows([
+            [1.0, 2.0, 3.0],
+            [4.0, 5.0, 6.0],
+            [7.0, 8.0, 9.0]]);
+
+        // Act
+        let lu = matrix.lu();
+
+        // Assert
+        assert!(lu.is_singular());
+        assert!(matrix.inverse().is_none());
+        assert!(matrix.solve(Vector::from_array([1.0, 2.0, 3.0])).is_none());
+    }
+
+    #[test]
+    fn test_inverse() {
+        // Arrange
+        let matrix = Matrix3x3::new(
+            4.0, 7.0, 2.0,
+            3.0, 6.0, 1.0,
+            2.0, 5.0, 3.0);
+
+        // Act
+        let product = matrix.mul(matrix.inverse().unwrap());
+
+        // Assert
+        for row in 0..3 {
+            for column in 0..3 {
+                assert!( approx_eq!(f32, product[[row, column]], Matrix3x3::identity()[[row, column]], epsilon = 0.0001) );
+            }
+        }
+    }
+
+    #[test]
+    fn test_inverse_of_small_object_far_from_origin() {
+        // Arrange
+        // The translation is millions of times larger than the scale, but the matrix is far from singular
+        let matrix = Matrix4x4::identity().translate(Vector2::new(1e6, 1e6)).scale(0.3, 0.3, 0.3);
+        let point = Vector3::new(1e6 + 0.3, 1e6 - 0.6, 0.9);
+
+        // Act
+        let inverse = matrix.inverse().unwrap();
+        let plane = Plane::new(Vector3::new(0.0, 0.0, 1.0), 0.0).transform(&matrix);
+
+        // Assert
+        assert!( approx_eq!(f32, matrix.determinant(), 0.027, epsilon = 0.0001) );
+        assert!( approx_eq!(f32, (inverse.transform_point(point) - Vector3::new(1.0, -2.0, 3.0)).length(), 0.0, epsilon = 0.01) );
+        assert!(plane.is_some());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_dynamic_matrix() {
+        // Arrange
+        let matrix = DMatrix::from_rows_slice(3, 3, &[
+            2.0, 1.0, -1.0,
+            -3.0, -1.0, 2.0,
+            -2.0, 1.0, 2.0]);
+        let singular_matrix = DMatrix::from_rows_slice(2, 2, &[1.0, 2.0, 2.0, 4.0]);
+
+        // Act
+        let solution = matrix.solve(&DVector::from_slice(&[8.0, -11.0, -3.0])).unwrap();
+        let product = &matrix * &matrix.inverse().unwrap();
+
+        // Assert
+        assert!( approx_eq!(f64, solution[0], 2.0, epsilon = 0.0001) );
+        assert!( approx_eq!(f64, solution[1], 3.0, epsilon = 0.0001) );
+        assert!( approx_eq!(f64, solution[2], -1.0, epsilon = 0.0001) );
+        assert!( approx_eq!(f64, matrix.determinant(), -1.0, epsilon = 0.0001) );
+        assert!(singular_matrix.inverse().is_none());
+
+        for row in 0..3 {
+            for column in 0..3 {
+                assert!( approx_eq!(f64, product[[row, column]], DMatrix::<f64>::identity(3)[[row, column]], epsilon = 0.0001) );
+            }
+        }
+    }
+}
@@ -0,0 +1,78 @@
+// The float functions that are not part of core, such as sqrt and the trigonometric functions, are implemented by std.
+// Without std, the same functions are taken from the libm crate instead, which is a port of the C math library to Rust.
+// All code in the crate calls these instead of the methods on f32 and f64, so that it works both with and without std.
+macro_rules! math_functions {
+    ($($name:ident($type:ty) => $std_method:ident, $libm_function:ident;)+) => {
+        $(
+            #[inline]
+            pub(crate) fn $name(x: $type) -> $type {
+                #[cfg(feature = "std")]
+                return x.$std_method();
+
+                #[cfg(not(feature = "std"))]
+                return libm::$libm_function(x);
+            }
+        )+
+    };
+}
+
+math_functions! {
+    sqrtf(f32) => sqrt, sqrtf;
+    sqrt(f64) => sqrt, sqrt;
+    sinf(f32) => sin, sinf;
+    sin(f64) => sin, sin;
+    cosf(f32) => cos, cosf;
+    cos(f64) => cos, cos;
+    tanf(f32) => tan, tanf;
+    tan(f64) => tan, tan;
+    absf(f32) => abs, fabsf;
+    abs(f64) => abs, fabs;
+    floorf(f32) => floor, floorf;
+    floor(f64) => floor, floor;
+    ceilf(f32) => ceil, ceilf;
+    ceil(f64) => ceil, ceil;
+    roundf(f32) => round, roundf;
+    round(f64) => round, round;
+}
+
+// atan2 takes two arguments, so it doesn't fit the macro above
+#[inline]
+pub(crate) fn atan2f(y: f32, x: f32) -> f32 {
+    #[cfg(feature = "std")]
+    return y.atan2(x);
+
+    #[cfg(not(feature = "std"))]
+    return libm::atan2f(y, x);
+}
+
+#[inline]
+pub(crate) fn atan2(y: f64, x: f64) -> f64 {
+    #[cfg(feature = "std")]
+    return y.atan2(x);
+
+    #[cfg(not(feature = "std"))]
+    return libm::atan2(y, x);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use float_cmp::approx_eq;
+
+    #[test]
+    fn test_math_functions() {
+        // Act & Assert
+        assert_eq!(sqrtf(16.0), 4.0);
+        assert_eq!(sqrt(2.25), 1.5);
+        assert!( approx_eq!(f32, sinf(core::f32::consts::FRAC_PI_2), 1.0, epsilon = 0.0001) );
+        assert!( approx_eq!(f64, cos(core::f64::consts::PI), -1.0, epsilon = 0.0001) );
+        assert_eq!(absf(-2.5), 2.5);
+        assert_eq!(floor(-2.5), -3.0);
+        assert_eq!(ceilf(-2.5), -2.0);
+        // Halfway cases round away from zero, the same as in std
+        assert_eq!(roundf(-2.5), -3.0);
+        assert_eq!(round(0.5), 1.0);
+        assert!( approx_eq!(f32, atan2f(1.0, -1.0), 3.0 * core::f32::consts::FRAC_PI_4, epsilon = 0.0001) );
+        assert!( approx_eq!(f64, atan2(-1.0, 0.0), -core::f64::consts::FRAC_PI_2, epsilon = 0.0001) );
+    }
+}
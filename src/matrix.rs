@@ -0,0 +1,721 @@
+use core::fmt;
+use core::str::FromStr;
+use core::ops::{Add, Sub, Mul, Neg, Rem, Shl, Shr, Index, IndexMut};
+
+use crate::sqrt_trait::Sqrt;
+use crate::float_trait::Float;
+use crate::integer_trait::{Integer, f32_to_i32, f32_to_u32};
+use crate::interpolation::Lerp;
+use crate::parse::{ParseError, parse_each_component};
+
+// A matrix with R rows and C columns, where the sizes are part of the type.
+// This means that functionality such as multiplication and transposing is written once and works for every size,
+// and that multiplying matrices of mismatched sizes is a compile error instead of a panic at run-time.
+// Like Matrix4x4 always has been, the elements are stored in column-major order: "columns[c][r]" is the element in row r and column c.
+// "repr(C)" guarantees that the matrix has the same layout as R * C elements in a flat array.
+// Comparing with < and > orders matrices by their elements in column-major order, so vectors are ordered by x first, then y, and so on.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(C)]
+pub struct Matrix<T, const R: usize, const C: usize> where T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy {
+    pub(crate) columns: [[T; R]; C]
+}
+
+// A column vector is a matrix with a single column.
+// Vector2, Vector3 and Vector4 are this type for 2, 3 and 4 components, so everything written here works for them as well.
+pub type Vector<T, const N: usize> = Matrix<T, N, 1>;
+
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy, const R: usize, const C: usize> Matrix<T, R, C> {
+    pub fn from_cols(columns: [[T; R]; C]) -> Self {
+        Matrix {
+            columns
+        }
+    }
+
+    pub fn from_rows(rows: [[T; C]; R]) -> Self {
+        Matrix::<T, C, R>::from_cols(rows).transpose()
+    }
+
+    // Creates a matrix by calling "f" with the row and column of every element
+    pub fn from_fn<F: FnMut(usize, usize) -> T>(mut f: F) -> Self {
+        Matrix {
+            columns: core::array::from_fn(|column| core::array::from_fn(|row| f(row, column)))
+        }
+    }
+
+    pub fn to_cols(&self) -> [[T; R]; C] {
+        self.columns
+    }
+
+    pub fn to_rows(&self) -> [[T; C]; R] {
+        self.transpose().columns
+    }
+
+    pub fn column(&self, column: usize) -> Vector<T, R> {
+        Matrix::from_cols([self.columns[column]])
+    }
+
+    pub fn row(&self, row: usize) -> Matrix<T, 1, C> {
+        Matrix::from_fn(|_, column| self.columns[column][row])
+    }
+
+    pub fn transpose(&self) -> Matrix<T, C, R> {
+        Matrix::from_fn(|row, column| self[[column, row]])
+    }
+
+    // Multiplies an R x C matrix with a C x K matrix, which gives an R x K matrix.
+    // The sizes are checked when compiling, so for example a 4x4 matrix can be multiplied with a 4x1 vector, but not with a 3x1 vector.
+    pub fn mul<const K: usize>(&self, other: Matrix<T, C, K>) -> Matrix<T, R, K> {
+        // Evaluated when compiling, so multiplying a matrix with zero columns is a compile error instead of an index out of range
+        const { assert!(C > 0, "Matrices with zero columns can't be multiplied!") };
+
+        Matrix::from_fn(|row, column| {
+            // There is at least one column, so the first product can be used as the starting point of the sum
+            let mut sum = self[[row, 0]] * other[[0, column]];
+
+            for k in 1..C {
+                sum = sum + self[[row, k]] * other[[k, column]];
+            }
+
+            sum
+        })
+    }
+
+    pub fn scalar_multiplication(self, scalar: T) -> Self {
+        self.map(|element| element * scalar)
+    }
+
+    // Element-wise multiplication, also known as the Hadamard product
+    pub fn component_mul(self, other: Self) -> Self {
+        Matrix::from_fn(|row, column| self[[row, column]] * other[[row, column]])
+    }
+
+    pub fn map<U, F>(self, mut f: F) -> Matrix<U, R, C>
+    where U: Add<Output = U> + Sub<Output = U> + Mul<Output = U> + Copy, F: FnMut(T) -> U {
+        Matrix::from_fn(|row, column| f(self[[row, column]]))
+    }
+
+    // Creates a matrix like from_fn, but returns None as soon as "f" returns None for any element
+    fn try_from_fn<F: FnMut(usize, usize) -> Option<T>>(mut f: F) -> Option<Self> {
+        let mut columns = [[None; R]; C];
+
+        for (column, elements) in columns.iter_mut().enumerate() {
+            for (row, element) in elements.iter_mut().enumerate() {
+                *element = Some(f(row, column)?);
+            }
+        }
+
+        // Every element has been set at this point, since a None returns early
+        Some(Matrix::from_cols(columns.map(|elements| elements.map(|element| element.unwrap()))))
+    }
+
+    // Views a slice of matrices as one slice of all their elements in column-major order, without copying.
+    // For vectors, this is for example how vertex positions are passed to a C API.
+    pub fn as_flat_slice(matrices: &[Self]) -> &[T] {
+        // SAFETY: The matrix is "repr(C)" with a single [[T; R]; C] field, and arrays have no padding between elements,
+        // so a slice of matrices has the same layout as R * C times as many T's.
+        unsafe { core::slice::from_raw_parts(matrices.as_ptr() as *const T, matrices.len() * R * C) }
+    }
+
+    pub fn as_flat_slice_mut(matrices: &mut [Self]) -> &mut [T] {
+        // SAFETY: See as_flat_slice
+        unsafe { core::slice::from_raw_parts_mut(matrices.as_mut_ptr() as *mut T, matrices.len() * R * C) }
+    }
+
+    // The opposite of as_flat_slice. Returns None if the number of elements is not a multiple of R * C.
+    pub fn from_flat_slice(elements: &[T]) -> Option<&[Self]> {
+        if !elements.len().is_multiple_of(R * C) {
+            return None;
+        }
+
+        // SAFETY: See as_flat_slice. The alignment of the matrix is the alignment of T, so any slice of T's is aligned correctly.
+        Some(unsafe { core::slice::from_raw_parts(elements.as_ptr() as *const Self, elements.len() / (R * C)) })
+    }
+}
+
+// Component-wise operations, named after their GLSL counterparts
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy + PartialOrd, const R: usize, const C: usize> Matrix<T, R, C> {
+    pub fn min(self, other: Self) -> Self {
+        Matrix::from_fn(|row, column| if other[[row, column]] < self[[row, column]] { other[[row, column]] } else { self[[row, column]] })
+    }
+
+    pub fn max(self, other: Self) -> Self {
+        Matrix::from_fn(|row, column| if other[[row, column]] > self[[row, column]] { other[[row, column]] } else { self[[row, column]] })
+    }
+
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        self.max(min).min(max)
+    }
+}
+
+impl<T: Float, const R: usize, const C: usize> Matrix<T, R, C> {
+    pub fn abs(self) -> Self {
+        self.map(T::abs)
+    }
+
+    pub fn floor(self) -> Self {
+        self.map(T::floor)
+    }
+
+    pub fn ceil(self) -> Self {
+        self.map(T::ceil)
+    }
+
+    pub fn round(self) -> Self {
+        self.map(T::round)
+    }
+
+    // The fractional part, "x - floor(x)". Like in GLSL, this is always positive, also for negative elements.
+    pub fn fract(self) -> Self {
+        self - self.floor()
+    }
+}
+
+// Component-wise arithmetic with explicit overflow behaviour. The checked variants return None if any element overflows.
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy + Integer, const R: usize, const C: usize> Matrix<T, R, C> {
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        Matrix::try_from_fn(|row, column| self[[row, column]].checked_add(other[[row, column]]))
+    }
+
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        Matrix::try_from_fn(|row, column| self[[row, column]].checked_sub(other[[row, column]]))
+    }
+
+    pub fn checked_mul(self, other: Self) -> Option<Self> {
+        Matrix::try_from_fn(|row, column| self[[row, column]].checked_mul(other[[row, column]]))
+    }
+
+    pub fn saturating_add(self, other: Self) -> Self {
+        Matrix::from_fn(|row, column| self[[row, column]].saturating_add(other[[row, column]]))
+    }
+
+    pub fn saturating_sub(self, other: Self) -> Self {
+        Matrix::from_fn(|row, column| self[[row, column]].saturating_sub(other[[row, column]]))
+    }
+
+    pub fn saturating_mul(self, other: Self) -> Self {
+        Matrix::from_fn(|row, column| self[[row, column]].saturating_mul(other[[row, column]]))
+    }
+
+    pub fn wrapping_add(self, other: Self) -> Self {
+        Matrix::from_fn(|row, column| self[[row, column]].wrapping_add(other[[row, column]]))
+    }
+
+    pub fn wrapping_sub(self, other: Self) -> Self {
+        Matrix::from_fn(|row, column| self[[row, column]].wrapping_sub(other[[row, column]]))
+    }
+
+    pub fn wrapping_mul(self, other: Self) -> Self {
+        Matrix::from_fn(|row, column| self[[row, column]].wrapping_mul(other[[row, column]]))
+    }
+}
+
+impl<const R: usize, const C: usize> Matrix<i32, R, C> {
+    // Integers above 2^24 in magnitude can't all be represented by an f32, and are rounded to the nearest representable value.
+    pub fn to_f32(self) -> Matrix<f32, R, C> {
+        self.map(|element| element as f32)
+    }
+}
+
+impl<const R: usize, const C: usize> Matrix<u32, R, C> {
+    // Integers above 2^24 in magnitude can't all be represented by an f32, and are rounded to the nearest representable value.
+    pub fn to_f32(self) -> Matrix<f32, R, C> {
+        self.map(|element| element as f32)
+    }
+}
+
+impl<const R: usize, const C: usize> Matrix<f32, R, C> {
+    // Truncates the elements towards zero. Returns None if any element is NaN, infinite or out of range.
+    pub fn try_to_i32(self) -> Option<Matrix<i32, R, C>> {
+        Matrix::try_from_fn(|row, column| f32_to_i32(self[[row, column]]))
+    }
+
+    // Truncates the elements towards zero. Returns None if any element is NaN, infinite or out of range.
+    pub fn try_to_u32(self) -> Option<Matrix<u32, R, C>> {
+        Matrix::try_from_fn(|row, column| f32_to_u32(self[[row, column]]))
+    }
+}
+
+// LEARN: Default Trait in Rust
+// The "Default" trait can be implemented as a way to easily fall back to a default value for a struct and its members.
+// It can be used in two ways:
+// - By deriving the default trait on the struct itself. This works if all members of the struct implements Default themselves.
+// - By manually implementing the method default()
+// I manually implement the method default here, since arrays only implement Default up to a size of 32, and not for any size given by const generics.
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy + Default, const R: usize, const C: usize> Default for Matrix<T, R, C> {
+    fn default() -> Self {
+        Matrix {
+            columns: [[T::default(); R]; C]
+        }
+    }
+}
+
+impl<T: Float, const N: usize> Matrix<T, N, N> {
+    pub fn identity() -> Self {
+        Matrix::from_fn(|row, column| if row == column { T::one() } else { T::zero() })
+    }
+}
+
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy, const N: usize> Matrix<T, N, 1> {
+    pub fn from_array(array: [T; N]) -> Self {
+        Matrix::from_cols([array])
+    }
+
+    pub fn to_array(self) -> [T; N] {
+        self.columns[0]
+    }
+
+    pub fn dot_product(self, other: Self) -> T {
+        self.transpose().mul(other)[[0, 0]]
+    }
+}
+
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy + Sqrt, const N: usize> Matrix<T, N, 1> {
+    pub fn length(self) -> T {
+        self.dot_product(self).sqrt()
+    }
+}
+
+// Lengths that are mostly useful for integer vectors such as tile or voxel coordinates, since they don't need a square root.
+// For integers, these overflow like ordinary integer arithmetic, for example for a component of i32::MIN, whose absolute value doesn't fit in an i32.
+// Use the checked variants below when the components can get that large.
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy + PartialOrd + Default, const N: usize> Matrix<T, N, 1> {
+    // The distance when only moving along the axes, the sum of the absolute components
+    pub fn manhattan_length(self) -> T {
+        self.to_array().iter().fold(T::default(), |length, &component| length + abs(component))
+    }
+
+    // The distance when diagonal moves count as a single step, the largest absolute component
+    pub fn chebyshev_length(self) -> T {
+        self.to_array().iter().fold(T::default(), |length, &component| if abs(component) > length { abs(component) } else { length })
+    }
+}
+
+// The absolute value for any type that can be compared with zero, which includes the unsigned integers that have no abs method
+fn abs<T: Sub<Output = T> + Copy + PartialOrd + Default>(value: T) -> T {
+    let zero = T::default();
+
+    if value < zero { zero - value } else { value }
+}
+
+// The same lengths, but returning None instead of overflowing
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy + PartialOrd + Default + Integer, const N: usize> Matrix<T, N, 1> {
+    pub fn checked_manhattan_length(self) -> Option<T> {
+        self.to_array().iter().try_fold(T::default(), |length, &component| length.checked_add(checked_abs(component)?))
+    }
+
+    pub fn checked_chebyshev_length(self) -> Option<T> {
+        self.to_array().iter().try_fold(T::default(), |length, &component| {
+            let component = checked_abs(component)?;
+
+            Some(if component > length { component } else { length })
+        })
+    }
+}
+
+fn checked_abs<T: Copy + PartialOrd + Default + Integer>(value: T) -> Option<T> {
+    let zero = T::default();
+
+    if value < zero { zero.checked_sub(value) } else { Some(value) }
+}
+
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy, const N: usize> From<[T; N]> for Matrix<T, N, 1> {
+    fn from(array: [T; N]) -> Self {
+        Matrix::from_array(array)
+    }
+}
+
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy, const N: usize> From<Matrix<T, N, 1>> for [T; N] {
+    fn from(vector: Matrix<T, N, 1>) -> [T; N] {
+        vector.to_array()
+    }
+}
+
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy, const R: usize, const C: usize> Index<[usize; 2]> for Matrix<T, R, C> {
+    type Output = T;
+
+    // Indexed as [row, column]
+    fn index(&self, index: [usize; 2]) -> &T {
+        let [requested_row, requested_column] = index;
+
+        // LEARN: I actually panic in library code here.
+        // Reason: Providing an index outside the range of the matrix represents a flat out incorrect call to this indexing method.
+        // The client is violating the preconditions of the function, and so I will refuse to even accept the input as it represents a bug in the calling code.
+        // This is NOT something that should occur run-time on the client-side.
+        // LEARN: Think about pros / cons about this in terms of users using the library... and what good alternatives could be.
+        // Definite pro: Client code gets to write quick, short and to-the-point indexing code that doesn't have to deal with a Result and unpacking it, etc, which can very quickly write long hard-to-read code
+        // If you're dealing with matrix math.
+        if requested_column >= C {
+            panic!("You requested column {}, but the max allowed index is {}!", requested_column, C - 1);
+        }
+
+        if requested_row >= R {
+            panic!("You requested row {}, but the max allowed index is {}!", requested_row, R - 1);
+        }
+
+        &self.columns[requested_column][requested_row]
+    }
+}
+
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy, const R: usize, const C: usize> IndexMut<[usize; 2]> for Matrix<T, R, C> {
+    fn index_mut(&mut self, index: [usize; 2]) -> &mut T {
+        let [requested_row, requested_column] = index;
+
+        if requested_column >= C {
+            panic!("You requested column {}, but the max allowed index is {}!", requested_column, C - 1);
+        }
+
+        if requested_row >= R {
+            panic!("You requested row {}, but the max allowed index is {}!", requested_row, R - 1);
+        }
+
+        &mut self.columns[requested_column][requested_row]
+    }
+}
+
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy, const R: usize, const C: usize> Add for Matrix<T, R, C> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Matrix::from_fn(|row, column| self[[row, column]] + other[[row, column]])
+    }
+}
+
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy, const R: usize, const C: usize> Sub for Matrix<T, R, C> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Matrix::from_fn(|row, column| self[[row, column]] - other[[row, column]])
+    }
+}
+
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy + Neg<Output = T>, const R: usize, const C: usize> Neg for Matrix<T, R, C> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        self.map(|element| -element)
+    }
+}
+
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy, const R: usize, const C: usize, const K: usize> Mul<Matrix<T, C, K>> for Matrix<T, R, C> {
+    type Output = Matrix<T, R, K>;
+
+    fn mul(self, other: Matrix<T, C, K>) -> Matrix<T, R, K> {
+        Matrix::mul(&self, other)
+    }
+}
+
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy + Rem<Output = T>, const R: usize, const C: usize> Rem for Matrix<T, R, C> {
+    type Output = Self;
+
+    fn rem(self, other: Self) -> Self {
+        Matrix::from_fn(|row, column| self[[row, column]] % other[[row, column]])
+    }
+}
+
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy + Shl<u32, Output = T>, const R: usize, const C: usize> Shl<u32> for Matrix<T, R, C> {
+    type Output = Self;
+
+    fn shl(self, bits: u32) -> Self {
+        self.map(|element| element << bits)
+    }
+}
+
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy + Shr<u32, Output = T>, const R: usize, const C: usize> Shr<u32> for Matrix<T, R, C> {
+    type Output = Self;
+
+    fn shr(self, bits: u32) -> Self {
+        self.map(|element| element >> bits)
+    }
+}
+
+// Component-wise interpolation. Note that interpolating rotation matrices this way does not give a rotation matrix in between.
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy, const R: usize, const C: usize> Lerp<T> for Matrix<T, R, C> {
+    fn lerp(self, other: Self, t: T) -> Self {
+        self + (other - self).scalar_multiplication(t)
+    }
+}
+
+// Counts the characters written to it. This lets the formatting code measure how wide a number will be printed, without allocating a String for it.
+struct CharacterCounter {
+    count: usize
+}
+
+impl fmt::Write for CharacterCounter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.count += s.chars().count();
+        Ok(())
+    }
+}
+
+fn write_debug_element<T: fmt::Debug>(writer: &mut dyn fmt::Write, value: &T, width: usize, precision: Option<usize>) -> fmt::Result {
+    match precision {
+        Some(precision) => write!(writer, "{:>width$.precision$?}", value, width = width, precision = precision),
+        None => write!(writer, "{:>width$?}", value, width = width)
+    }
+}
+
+fn write_display_element<T: fmt::Display>(writer: &mut dyn fmt::Write, value: &T, width: usize, precision: Option<usize>) -> fmt::Result {
+    match precision {
+        Some(precision) => write!(writer, "{:>width$.precision$}", value, width = width, precision = precision),
+        None => write!(writer, "{:>width$}", value, width = width)
+    }
+}
+
+type ElementWriter<T> = fn(&mut dyn fmt::Write, &T, usize, Option<usize>) -> fmt::Result;
+
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy, const R: usize, const C: usize> Matrix<T, R, C> {
+    // Writes the rows of the matrix as "[m00, m01, m02, m03]", either all on one line or one row per line.
+    // When printing one row per line, every column is padded to the width of its widest element so the columns line up.
+    // A width given in the format string, as in "{:8.3}", is used as the minimum width of every element.
+    // "debug" only changes how the rows are separated, "write_element" decides how each element is formatted.
+    fn write_rows(&self, f: &mut fmt::Formatter<'_>, write_element: ElementWriter<T>, debug: bool, multiline: bool) -> fmt::Result {
+        let precision = f.precision();
+        let mut widths = [f.width().unwrap_or(0); C];
+
+        if multiline {
+            for (column, width) in widths.iter_mut().enumerate() {
+                for row in 0..R {
+                    let mut counter = CharacterCounter { count: 0 };
+                    write_element(&mut counter, &self[[row, column]], 0, precision)?;
+
+                    if counter.count > *width {
+                        *width = counter.count;
+                    }
+                }
+            }
+        }
+
+        for row in 0..R {
+            if multiline && debug {
+                write!(f, "\n    ")?;
+            } else if multiline && row > 0 {
+                writeln!(f)?;
+            } else if row > 0 {
+                write!(f, ", ")?;
+            }
+
+            write!(f, "[")?;
+
+            for (column, width) in widths.iter().enumerate() {
+                if column > 0 {
+                    write!(f, ", ")?;
+                }
+
+                write_element(f, &self[[row, column]], *width, precision)?;
+            }
+
+            write!(f, "]")?;
+
+            if multiline && debug {
+                write!(f, ",")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// "{:?}" prints the matrix on a single line, and "{:#?}" prints one row per line.
+// The name includes the size, for example "Matrix4x4 [[...], ...]".
+// Vector2, Vector3 and Vector4 are printed like a struct with named fields instead, as in "Vector3 { x: 1.0, y: 2.0, z: 3.0 }".
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy + fmt::Debug, const R: usize, const C: usize> fmt::Debug for Matrix<T, R, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if C == 1 && (2..=4).contains(&R) {
+            let mut debug_struct = f.debug_struct(["Vector2", "Vector3", "Vector4"][R - 2]);
+
+            for (name, component) in ["x", "y", "z", "w"].iter().zip(self.columns[0].iter()) {
+                debug_struct.field(name, component);
+            }
+
+            return debug_struct.finish();
+        }
+
+        write!(f, "Matrix{}x{} [", R, C)?;
+
+        self.write_rows(f, write_debug_element, true, f.alternate())?;
+
+        if f.alternate() {
+            write!(f, "\n]")
+        } else {
+            write!(f, "]")
+        }
+    }
+}
+
+// "{}" prints one row per line with the columns aligned, and "{:#}" prints the compact form "[[...], [...], [...], [...]]" on a single line, which is handy for logs.
+// Vectors are printed as "(x, y, z)" instead. Any width and precision in the format string, as in "{:8.3}", applies to each component.
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy + fmt::Display, const R: usize, const C: usize> fmt::Display for Matrix<T, R, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if C == 1 {
+            let (width, precision) = (f.width().unwrap_or(0), f.precision());
+            write!(f, "(")?;
+
+            for (row, component) in self.columns[0].iter().enumerate() {
+                if row > 0 {
+                    write!(f, ", ")?;
+                }
+
+                write_display_element(f, component, width, precision)?;
+            }
+
+            return write!(f, ")");
+        }
+
+        if f.alternate() {
+            write!(f, "[")?;
+            self.write_rows(f, write_display_element, false, false)?;
+            write!(f, "]")
+        } else {
+            self.write_rows(f, write_display_element, false, true)
+        }
+    }
+}
+
+// Reads the R * C elements in row-major order, the same order as Matrix4x4::new takes them.
+// This accepts both forms written by Display: one bracketed row per line, and "[[...], [...], [...], [...]]" on a single line.
+// For vectors, that is "(x, y, z)" as well as the components without the parentheses.
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy + Default + FromStr, const R: usize, const C: usize> FromStr for Matrix<T, R, C> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut matrix = Matrix::default();
+
+        parse_each_component(s, R * C, |index, value| matrix[[index / C, index % C]] = value)?;
+
+        Ok(matrix)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vector2::Vector2;
+    use crate::vector4::Vector4;
+
+    #[test]
+    fn test_from_rows_and_cols() {
+        // Act
+        let from_rows = Matrix::<i32, 2, 3>::from_rows([[1, 2, 3], [4, 5, 6]]);
+        let from_cols = Matrix::<i32, 2, 3>::from_cols([[1, 4], [2, 5], [3, 6]]);
+
+        // Assert
+        assert_eq!(from_rows, from_cols);
+        assert_eq!(from_rows[[0, 2]], 3);
+        assert_eq!(from_rows.row(1), Matrix::from_rows([[4, 5, 6]]));
+        assert_eq!(from_rows.column(1), Vector::from([2, 5]));
+        assert_eq!(from_rows.to_rows(), [[1, 2, 3], [4, 5, 6]]);
+    }
+
+    #[test]
+    fn test_mul_with_different_sizes() {
+        // Arrange
+        let matrix_a = Matrix::<i32, 2, 3>::from_rows([[1, 2, 3], [4, 5, 6]]);
+        let matrix_b = Matrix::<i32, 3, 2>::from_rows([[7, 8], [9, 10], [11, 12]]);
+
+        // Act
+        let product = matrix_a * matrix_b;
+        let transformed_vector = matrix_a.mul(Vector::from([1, 0, 1]));
+
+        // Assert
+        assert_eq!(product, Matrix::from_rows([[58, 64], [139, 154]]));
+        assert_eq!(transformed_vector.to_array(), [4, 10]);
+    }
+
+    #[test]
+    fn test_transpose() {
+        // Arrange
+        let matrix = Matrix::<i32, 2, 3>::from_rows([[1, 2, 3], [4, 5, 6]]);
+
+        // Act
+        let transposed_matrix: Matrix<i32, 3, 2> = matrix.transpose();
+
+        // Assert
+        assert_eq!(transposed_matrix, Matrix::from_rows([[1, 4], [2, 5], [3, 6]]));
+        assert_eq!(transposed_matrix.transpose(), matrix);
+    }
+
+    #[test]
+    fn test_element_wise_operations() {
+        // Arrange
+        let matrix_a = Matrix::<i32, 2, 2>::from_rows([[1, 2], [3, 4]]);
+        let matrix_b = Matrix::<i32, 2, 2>::from_rows([[5, 6], [7, 8]]);
+
+        // Act & Assert
+        assert_eq!(matrix_a + matrix_b, Matrix::from_rows([[6, 8], [10, 12]]));
+        assert_eq!(matrix_b - matrix_a, Matrix::from_rows([[4, 4], [4, 4]]));
+        assert_eq!(-matrix_a, Matrix::from_rows([[-1, -2], [-3, -4]]));
+        assert_eq!(matrix_a.component_mul(matrix_b), Matrix::from_rows([[5, 12], [21, 32]]));
+        assert_eq!(matrix_a.scalar_multiplication(2), Matrix::from_rows([[2, 4], [6, 8]]));
+        assert_eq!(matrix_a.min(Matrix::from_rows([[0, 5], [5, 0]])), Matrix::from_rows([[0, 2], [3, 0]]));
+        assert_eq!(matrix_b % matrix_a, Matrix::from_rows([[0, 0], [1, 0]]));
+        assert_eq!(matrix_a.checked_mul(Matrix::from_rows([[1, 1], [1, i32::MAX]])), None);
+    }
+
+    #[test]
+    fn test_identity() {
+        // Act
+        let identity = Matrix::<f64, 3, 3>::identity();
+
+        // Assert
+        assert_eq!(identity.to_rows(), [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]);
+    }
+
+    #[test]
+    fn test_vector_operations() {
+        // Arrange
+        let vector_a = Vector::<f64, 5>::from([1.0, 2.0, 2.0, 0.0, 4.0]);
+        let vector_b = Vector::<f64, 5>::from([1.0, 0.0, 1.0, 1.0, 0.0]);
+
+        // Act & Assert
+        assert_eq!(vector_a.dot_product(vector_b), 3.0);
+        assert_eq!(vector_a.length(), 5.0);
+    }
+
+    #[test]
+    fn test_display_and_parse() {
+        // Arrange
+        let matrix = Matrix::<i32, 2, 3>::from_rows([[1, -20, 3], [4, 5, 600]]);
+
+        // Act
+        let display = format!("{}", matrix);
+        let compact_display = format!("{:#}", matrix);
+        let debug = format!("{:?}", matrix);
+        let parsed_matrix: Matrix<i32, 2, 3> = display.parse().unwrap();
+
+        // Assert
+        assert_eq!(display, "[1, -20,   3]\n[4,   5, 600]");
+        assert_eq!(compact_display, "[[1, -20, 3], [4, 5, 600]]");
+        assert_eq!(debug, "Matrix2x3 [[1, -20, 3], [4, 5, 600]]");
+        assert_eq!(parsed_matrix, matrix);
+    }
+
+    #[test]
+    fn test_flat_slice_view() {
+        // Arrange
+        let matrices = [Matrix::<u32, 2, 2>::from_rows([[1, 2], [3, 4]]), Matrix::<u32, 2, 2>::from_rows([[5, 6], [7, 8]])];
+
+        // Act
+        let elements = Matrix::as_flat_slice(&matrices);
+
+        // Assert
+        assert_eq!(elements, &[1, 3, 2, 4, 5, 7, 6, 8]);
+    }
+
+    #[test]
+    fn test_named_vectors_are_generic_vectors() {
+        // Arrange
+        let matrix = crate::matrix4x4::Matrix4x4::identity().scale(2.0, 3.0, 4.0);
+        let vector = Vector4::new(1.0, 1.0, 1.0, 1.0);
+
+        // Act
+        let transformed_vector: Vector4<f32> = matrix * vector;
+        let generic_vector: Vector<i32, 2> = Vector2::new(5, 7);
+
+        // Assert
+        assert_eq!(transformed_vector, Vector4::new(2.0, 3.0, 4.0, 1.0));
+        assert_eq!(transformed_vector.z, 4.0);
+        assert_eq!(generic_vector.to_array(), [5, 7]);
+        assert_eq!(format!("{:?}", generic_vector), "Vector2 { x: 5, y: 7 }");
+        assert_eq!(format!("{}", Vector::from([1, 2, 3, 4, 5])), "(1, 2, 3, 4, 5)");
+    }
+}
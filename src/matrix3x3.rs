@@ -0,0 +1,147 @@
+use crate::vector3::Vector3;
+use crate::matrix4x4::Matrix4x4;
+use crate::matrix::Matrix;
+
+// A 3x3 matrix, mostly useful for rotations and for the normal matrix used in lighting.
+// Like Matrix4x4 it is stored in column-major order.
+pub type Matrix3x3 = Matrix<f32, 3, 3>;
+
+impl Matrix3x3 {
+    // The elements are given in row-major order, so that the call reads like the matrix it creates
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        m00: f32, m01: f32, m02: f32,
+        m10: f32, m11: f32, m12: f32,
+        m20: f32, m21: f32, m22: f32) -> Matrix3x3 {
+            Matrix::from_cols([
+                [m00, m10, m20],
+                [m01, m11, m21],
+                [m02, m12, m22]])
+    }
+
+    // The elements in column-major order, the same as for Matrix4x4
+    pub fn from_cols_array(array: [f32; 9]) -> Matrix3x3 {
+        Matrix::from_fn(|row, column| array[column * 3 + row])
+    }
+
+    pub fn from_rows_array(array: [f32; 9]) -> Matrix3x3 {
+        Matrix3x3::from_cols_array(array).transpose()
+    }
+
+    pub fn from_cols_array_2d(columns: [[f32; 3]; 3]) -> Matrix3x3 {
+        Matrix::from_cols(columns)
+    }
+
+    pub fn to_cols_array(&self) -> [f32; 9] {
+        *self.as_cols_array()
+    }
+
+    pub fn to_rows_array(&self) -> [f32; 9] {
+        self.transpose().to_cols_array()
+    }
+
+    pub fn to_cols_array_2d(&self) -> [[f32; 3]; 3] {
+        self.columns
+    }
+
+    pub fn as_cols_array(&self) -> &[f32; 9] {
+        // SAFETY: [[f32; 3]; 3] has the same layout as [f32; 9], since arrays have no padding between their elements
+        unsafe { &*(self.columns.as_ptr() as *const [f32; 9]) }
+    }
+
+    // The upper-left 3x3 part of the matrix, which is the matrix without its translation
+    pub fn from_matrix4x4(matrix: &Matrix4x4) -> Matrix3x3 {
+        Matrix3x3::new(
+            matrix[[0, 0]], matrix[[0, 1]], matrix[[0, 2]],
+            matrix[[1, 0]], matrix[[1, 1]], matrix[[1, 2]],
+            matrix[[2, 0]], matrix[[2, 1]], matrix[[2, 2]])
+    }
+
+    pub fn mul_vector3(&self, vector: Vector3<f32>) -> Vector3<f32> {
+        Vector3::new(
+            self[[0, 0]] * vector.x + self[[0, 1]] * vector.y + self[[0, 2]] * vector.z,
+            self[[1, 0]] * vector.x + self[[1, 1]] * vector.y + self[[1, 2]] * vector.z,
+            self[[2, 0]] * vector.x + self[[2, 1]] * vector.y + self[[2, 2]] * vector.z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vector2::Vector2;
+
+    #[test]
+    fn test_new_is_row_major() {
+        // Act
+        let matrix = Matrix3x3::new(
+            1.0, 2.0, 3.0,
+            4.0, 5.0, 6.0,
+            7.0, 8.0, 9.0);
+
+        // Assert
+        assert_eq!(matrix[[0, 2]], 3.0);
+        assert_eq!(matrix[[2, 0]], 7.0);
+        assert_eq!(matrix.transpose()[[0, 2]], 7.0);
+    }
+
+    #[test]
+    fn test_from_matrix4x4() {
+        // Arrange
+        let matrix4x4 = Matrix4x4::identity().translate(Vector2::new(5.0, 7.0)).scale(2.0, 3.0, 4.0);
+
+        // Act
+        let matrix = Matrix3x3::from_matrix4x4(&matrix4x4);
+
+        // Assert
+        assert!(matrix == Matrix3x3::new(
+            2.0, 0.0, 0.0,
+            0.0, 3.0, 0.0,
+            0.0, 0.0, 4.0));
+    }
+
+    #[test]
+    fn test_mul() {
+        // Arrange
+        let matrix_a = Matrix3x3::new(
+            1.0, 2.0, 0.0,
+            0.0, 1.0, 0.0,
+            0.0, 0.0, 1.0);
+        let matrix_b = Matrix3x3::new(
+            1.0, 0.0, 0.0,
+            3.0, 1.0, 0.0,
+            0.0, 0.0, 1.0);
+
+        // Act
+        let product = matrix_a.mul(matrix_b);
+        let transformed_vector = matrix_a.mul_vector3(Vector3::new(1.0, 1.0, 1.0));
+
+        // Assert
+        assert_eq!(product[[0, 0]], 7.0);
+        assert_eq!(product[[0, 1]], 2.0);
+        assert_eq!(product[[1, 0]], 3.0);
+        assert_eq!(transformed_vector, Vector3::new(3.0, 1.0, 1.0));
+    }
+
+    #[test]
+    fn test_memory_layout() {
+        // Act & Assert
+        assert_eq!(std::mem::size_of::<Matrix3x3>(), 36);
+        assert_eq!(std::mem::align_of::<Matrix3x3>(), 4);
+    }
+
+    #[test]
+    fn test_array_conversions() {
+        // Arrange
+        let matrix = Matrix3x3::new(
+            1.0, 2.0, 3.0,
+            4.0, 5.0, 6.0,
+            7.0, 8.0, 9.0);
+
+        // Act & Assert
+        assert_eq!(matrix.to_cols_array(), [1.0, 4.0, 7.0, 2.0, 5.0, 8.0, 3.0, 6.0, 9.0]);
+        assert_eq!(matrix.to_rows_array(), [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+        assert_eq!(matrix.to_cols_array_2d()[2], [3.0, 6.0, 9.0]);
+        assert!(Matrix3x3::from_rows_array(matrix.to_rows_array()) == matrix);
+        assert!(Matrix3x3::from_cols_array_2d(matrix.to_cols_array_2d()) == matrix);
+    }
+}
@@ -1,82 +1,61 @@
 use crate::vector2::{Vector2};
+use crate::vector3::Vector3;
+use crate::vector4::Vector4;
+use crate::plane::Plane;
+use crate::matrix::Matrix;
+use crate::math;
 
-use std::fmt;
-use std::ops::{Index};
+// The 4x4 matrix used for transformations in 3D. It is a Matrix like any other size, so everything implemented for Matrix works for it,
+// and the functions in this file add what is specific to 4x4 transformation matrices.
+pub type Matrix4x4 = Matrix<f32, 4, 4>;
 
-pub struct Matrix4x4 {
-    // The matrix is represented as a one-dimensional arrray in column-major order
-    // So far I haven't found a good reason to use a multi-dimensional array in code
-    array: [f32; 16]
-}
+impl Matrix4x4 {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        m00: f32, m01: f32, m02: f32, m03: f32,
+        m10: f32, m11: f32, m12: f32, m13: f32,
+        m20: f32, m21: f32, m22: f32, m23: f32,
+        m30: f32, m31: f32, m32: f32, m33: f32) -> Matrix4x4 {
+            Matrix::from_cols([
+                [m00, m10, m20, m30],
+                [m01, m11, m21, m31],
+                [m02, m12, m22, m32],
+                [m03, m13, m23, m33]])
+    }
 
-// LEARN: Default Trait in Rust
-// The "Default" trait can be implemented as a way to easily fall back to a default value for a struct and its members.
-// It can be used in two ways:
-// - By deriving the default trait on the struct itself. This works if all members of the struct implements Default themselves.
-// - By manually implementing the method default()
-// I manually implement the method default here
-// TODO: From what I understand, I don't actually have to implement Default here. Arrays should be able to be Default so long as they are under 32? in size and use Default types. 
-impl Default for Matrix4x4 {
-    fn default() -> Self {
-        Matrix4x4 {
-            array: [
-                0.0, 0.0, 0.0, 0.0, 
-                0.0, 0.0, 0.0, 0.0, 
-                0.0, 0.0, 0.0, 0.0, 
-                0.0, 0.0, 0.0, 0.0 ]
-        }
+    // The elements in column-major order, which is the order they are stored in, and the order OpenGL expects
+    pub fn from_cols_array(array: [f32; 16]) -> Matrix4x4 {
+        Matrix::from_fn(|row, column| array[column * 4 + row])
     }
-}
 
-impl Index<[usize; 2]> for Matrix4x4 {
-    // TODO: Gotta learn exactly what this Output pattern is about when implementing some traits..
-    type Output = f32;
-
-    fn index(&self, index: [usize; 2]) -> &f32 {
-        let requested_column = index[1];
-        let requested_row = index[0];
-
-        // LEARN: I actually panic in library code here.
-        // Reason: Providing an index outside the range of the 4x4 matrix represents a flat out incorrect call to this indexing method.
-        // The client is violating the preconditions of the function, and so I will refuse to even accept the input as it represents a bug in the calling code.
-        // This is NOT something that should occur run-time on the client-side.
-        // LEARN: Think about pros / cons about this in terms of users using the library... and what good alternatives could be.
-        // Definite pro: Client code gets to write quick, short and to-the-point indexing code that doesn't have to deal with a Result and unpacking it, etc, which can very quickly write long hard-to-read code
-        // If you're dealing with matrix math.
-        if requested_column > 3 {
-            panic!("You requested column {}, but the max allowed index is 3!", requested_column);
-        }
+    pub fn from_rows_array(array: [f32; 16]) -> Matrix4x4 {
+        Matrix4x4::from_cols_array(array).transpose()
+    }
 
-        if requested_row > 3 {
-            panic!("You requested row {}, but the max allowed index is 3!", requested_column);
-        }
+    pub fn from_cols_array_2d(columns: [[f32; 4]; 4]) -> Matrix4x4 {
+        Matrix::from_cols(columns)
+    }
 
-        &self.array[ requested_column * 4 + requested_row  ]
+    pub fn to_cols_array(&self) -> [f32; 16] {
+        *self.as_cols_array()
     }
-}
 
-impl Matrix4x4 {
-    pub fn new(
-        m00: f32, m01: f32, m02: f32, m03: f32,
-        m10: f32, m11: f32, m12: f32, m13: f32,
-        m20: f32, m21: f32, m22: f32, m23: f32,
-        m30: f32, m31: f32, m32: f32, m33: f32) -> Matrix4x4 {
-            Matrix4x4 {
-                array: [ 
-                    m00, m10, m20, m30, m01, m11, m21, m31, m02, m12, m22, m32, m03, m13, m23, m33 ]
-            }
+    pub fn to_rows_array(&self) -> [f32; 16] {
+        self.transpose().to_cols_array()
     }
 
-    pub fn first(&self) -> &f32 {
-        &self.array[0]
+    pub fn to_cols_array_2d(&self) -> [[f32; 4]; 4] {
+        self.columns
     }
 
-    pub fn identity() -> Matrix4x4 {
-        Matrix4x4::new(
-            1.0, 0.0, 0.0, 0.0,
-            0.0, 1.0, 0.0, 0.0,
-            0.0, 0.0, 1.0, 0.0,
-            0.0, 0.0, 0.0, 1.0)
+    // A view of the elements in column-major order, without copying
+    pub fn as_cols_array(&self) -> &[f32; 16] {
+        // SAFETY: [[f32; 4]; 4] has the same layout as [f32; 16], since arrays have no padding between their elements
+        unsafe { &*(self.columns.as_ptr() as *const [f32; 16]) }
+    }
+
+    pub fn first(&self) -> &f32 {
+        &self.columns[0][0]
     }
 
     // TODO: Should take a reference to a vector2 instead of moving it
@@ -92,10 +71,10 @@ impl Matrix4x4 {
     }
 
     // TODO: Only implemented roll axis rotation for this eulear angle rotation
-    pub fn rotate(&self, head: f32, pitch: f32, roll: f32) -> Matrix4x4 {
+    pub fn rotate(&self, _head: f32, _pitch: f32, roll: f32) -> Matrix4x4 {
         let roll_matrix = Matrix4x4::new(
-            roll.cos(), -roll.sin(), 0.0, 0.0, 
-            roll.sin(), roll.cos(), 0.0, 0.0, 
+            math::cosf(roll), -math::sinf(roll), 0.0, 0.0, 
+            math::sinf(roll), math::cosf(roll), 0.0, 0.0, 
             0.0, 0.0, 1.0, 0.0, 
             0.0, 0.0, 0.0, 1.0
         );
@@ -115,64 +94,56 @@ impl Matrix4x4 {
     }
 
     pub fn orthographic(left: f32, right: f32, bottom: f32, top: f32, near: f32, far: f32) -> Matrix4x4 {
-        let orthographic_projection = Matrix4x4::new(
+        Matrix4x4::new(
             2.0 / (right - left), 0.0,                0.0,              -((right + left)/(right - left)), 
             0.0,                  2.0/(top - bottom), 0.0,              -((top + bottom)/(top - bottom)), 
             0.0,                  0.0,                2.0/(far - near), -((far + near)/(far - near)), 
-            0.0,                  0.0,                0.0,              1.0);
-
-        orthographic_projection
+            0.0,                  0.0,                0.0,              1.0)
     }
 
-    // TODO: Should take a reference to another matrix instead of moving it
-    pub fn mul(&self, matrix4x4: Matrix4x4) -> Matrix4x4 {
-        // TODO: Now I don't know much about SIMD instructions yet, but it might have something to do with doing calculations in single instructions...
-        // That is definitely not what I'm doing here (I think???? Maybe???). Have to read up on all that at some point.
-        // For now I have bigger fish to fry.
-        let m00 = self[[0, 0]] * matrix4x4[[0, 0]] + self[[0, 1]] * matrix4x4[[1, 0]] + self[[0, 2]] * matrix4x4[[2, 0]] + self[[0, 3]] * matrix4x4[[3, 0]];
-        let m01 = self[[0, 0]] * matrix4x4[[0, 1]] + self[[0, 1]] * matrix4x4[[1, 1]] + self[[0, 2]] * matrix4x4[[2, 1]] + self[[0, 3]] * matrix4x4[[3, 1]];
-        let m02 = self[[0, 0]] * matrix4x4[[0, 2]] + self[[0, 1]] * matrix4x4[[1, 2]] + self[[0, 2]] * matrix4x4[[2, 2]] + self[[0, 3]] * matrix4x4[[3, 2]];
-        let m03 = self[[0, 0]] * matrix4x4[[0, 3]] + self[[0, 1]] * matrix4x4[[1, 3]] + self[[0, 2]] * matrix4x4[[2, 3]] + self[[0, 3]] * matrix4x4[[3, 3]];
-
-        let m10 = self[[1, 0]] * matrix4x4[[0, 0]] + self[[1, 1]] * matrix4x4[[1, 0]] + self[[1, 2]] * matrix4x4[[2, 0]] + self[[1, 3]] * matrix4x4[[3, 0]];
-        let m11 = self[[1, 0]] * matrix4x4[[0, 1]] + self[[1, 1]] * matrix4x4[[1, 1]] + self[[1, 2]] * matrix4x4[[2, 1]] + self[[1, 3]] * matrix4x4[[3, 1]];
-        let m12 = self[[1, 0]] * matrix4x4[[0, 2]] + self[[1, 1]] * matrix4x4[[1, 2]] + self[[1, 2]] * matrix4x4[[2, 2]] + self[[1, 3]] * matrix4x4[[3, 2]];
-        let m13 = self[[1, 0]] * matrix4x4[[0, 3]] + self[[1, 1]] * matrix4x4[[1, 3]] + self[[1, 2]] * matrix4x4[[2, 3]] + self[[1, 3]] * matrix4x4[[3, 3]];
+    // Builds the matrix that mirrors points across the given plane, as used for planar reflections.
+    // For a unit normal n, the linear part is I - 2nn^T, and points are additionally moved by -2dn.
+    pub fn reflection(plane: &Plane<f32>) -> Matrix4x4 {
+        let plane = plane.normalize();
+        let (a, b, c, d) = (plane.normal.x, plane.normal.y, plane.normal.z, plane.distance);
 
-        let m20 = self[[2, 0]] * matrix4x4[[0, 0]] + self[[2, 1]] * matrix4x4[[1, 0]] + self[[2, 2]] * matrix4x4[[2, 0]] + self[[2, 3]] * matrix4x4[[3, 0]];
-        let m21 = self[[2, 0]] * matrix4x4[[0, 1]] + self[[2, 1]] * matrix4x4[[1, 1]] + self[[2, 2]] * matrix4x4[[2, 1]] + self[[2, 3]] * matrix4x4[[3, 1]];
-        let m22 = self[[2, 0]] * matrix4x4[[0, 2]] + self[[2, 1]] * matrix4x4[[1, 2]] + self[[2, 2]] * matrix4x4[[2, 2]] + self[[2, 3]] * matrix4x4[[3, 2]];
-        let m23 = self[[2, 0]] * matrix4x4[[0, 3]] + self[[2, 1]] * matrix4x4[[1, 3]] + self[[2, 2]] * matrix4x4[[2, 3]] + self[[2, 3]] * matrix4x4[[3, 3]];
-
-        let m30 = self[[3, 0]] * matrix4x4[[0, 0]] + self[[3, 1]] * matrix4x4[[1, 0]] + self[[3, 2]] * matrix4x4[[2, 0]] + self[[3, 3]] * matrix4x4[[3, 0]];
-        let m31 = self[[3, 0]] * matrix4x4[[0, 1]] + self[[3, 1]] * matrix4x4[[1, 1]] + self[[3, 2]] * matrix4x4[[2, 1]] + self[[3, 3]] * matrix4x4[[3, 1]];
-        let m32 = self[[3, 0]] * matrix4x4[[0, 2]] + self[[3, 1]] * matrix4x4[[1, 2]] + self[[3, 2]] * matrix4x4[[2, 2]] + self[[3, 3]] * matrix4x4[[3, 2]];
-        let m33 = self[[3, 0]] * matrix4x4[[0, 3]] + self[[3, 1]] * matrix4x4[[1, 3]] + self[[3, 2]] * matrix4x4[[2, 3]] + self[[3, 3]] * matrix4x4[[3, 3]];
+        Matrix4x4::new(
+            1.0 - 2.0 * a * a, -2.0 * a * b,       -2.0 * a * c,       -2.0 * a * d,
+            -2.0 * b * a,       1.0 - 2.0 * b * b, -2.0 * b * c,       -2.0 * b * d,
+            -2.0 * c * a,       -2.0 * c * b,       1.0 - 2.0 * c * c, -2.0 * c * d,
+            0.0,                0.0,                0.0,                1.0)
+    }
 
-        let concatenated_matrix = Matrix4x4::new(
-            m00, m01, m02, m03, 
-            m10, m11, m12, m13, 
-            m20, m21, m22, m23, 
-            m30, m31, m32, m33);
+    // Transforms a point, which is affected by translation (its implicit w component is 1).
+    // No division by w is performed, so this is meant for affine transformations.
+    pub fn transform_point(&self, point: Vector3<f32>) -> Vector3<f32> {
+        Vector3::new(
+            self[[0, 0]] * point.x + self[[0, 1]] * point.y + self[[0, 2]] * point.z + self[[0, 3]],
+            self[[1, 0]] * point.x + self[[1, 1]] * point.y + self[[1, 2]] * point.z + self[[1, 3]],
+            self[[2, 0]] * point.x + self[[2, 1]] * point.y + self[[2, 2]] * point.z + self[[2, 3]])
+    }
 
-        concatenated_matrix
+    // Transforms a direction, which is NOT affected by translation (its implicit w component is 0).
+    pub fn transform_vector(&self, vector: Vector3<f32>) -> Vector3<f32> {
+        Vector3::new(
+            self[[0, 0]] * vector.x + self[[0, 1]] * vector.y + self[[0, 2]] * vector.z,
+            self[[1, 0]] * vector.x + self[[1, 1]] * vector.y + self[[1, 2]] * vector.z,
+            self[[2, 0]] * vector.x + self[[2, 1]] * vector.y + self[[2, 2]] * vector.z)
     }
-}
 
-impl fmt::Debug for Matrix4x4 {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        // TODO: Can you call multiple write! macros per line instead of having all in one call??
-        write!(f, "{},{},{},{}\n{},{},{},{}\n{},{},{},{}\n{},{},{},{}",
-                self[[0, 0]], self[[0, 1]], self[[0, 2]], self[[0, 3]],
-                self[[1, 0]], self[[1, 1]], self[[1, 2]], self[[1, 3]],
-                self[[2, 0]], self[[2, 1]], self[[2, 2]], self[[2, 3]],
-                self[[3, 0]], self[[3, 1]], self[[3, 2]], self[[3, 3]])
+    pub fn mul_vector4(&self, vector: Vector4<f32>) -> Vector4<f32> {
+        Vector4::new(
+            self[[0, 0]] * vector.x + self[[0, 1]] * vector.y + self[[0, 2]] * vector.z + self[[0, 3]] * vector.w,
+            self[[1, 0]] * vector.x + self[[1, 1]] * vector.y + self[[1, 2]] * vector.z + self[[1, 3]] * vector.w,
+            self[[2, 0]] * vector.x + self[[2, 1]] * vector.y + self[[2, 2]] * vector.z + self[[2, 3]] * vector.w,
+            self[[3, 0]] * vector.x + self[[3, 1]] * vector.y + self[[3, 2]] * vector.z + self[[3, 3]] * vector.w)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::interpolation::Lerp;
     use float_cmp::approx_eq;
 
     #[test]
@@ -227,6 +198,128 @@ mod tests {
         println!("{:?}", multiplication_result);
     }
 
+    #[test]
+    fn test_transpose() {
+        // Arrange
+        let matrix_a = Matrix4x4::new(
+            1.0, 2.0, 3.0, 4.0, 
+            5.0, 6.0, 7.0, 8.0, 
+            9.0, 10.0, 11.0, 12.0, 
+            13.0, 14.0, 15.0, 16.0);
+
+        // Act
+        let transposed_matrix = matrix_a.transpose();
+
+        // Assert
+        assert_eq!(transposed_matrix[[0, 1]], 5.0);
+        assert_eq!(transposed_matrix[[1, 0]], 2.0);
+        assert_eq!(transposed_matrix[[3, 2]], 12.0);
+        assert_eq!(transposed_matrix[[2, 3]], 15.0);
+    }
+
+    #[test]
+    fn test_inverse() {
+        // Arrange
+        let matrix_a = Matrix4x4::identity()
+            .translate(Vector2::new(3.0, -2.0))
+            .rotate(0.0, 0.0, 0.7)
+            .scale(2.0, 4.0, 0.5);
+
+        // Act
+        let inverse_matrix = matrix_a.inverse().unwrap();
+        let product = matrix_a.mul(inverse_matrix);
+
+        // Assert
+        let identity = Matrix4x4::identity();
+
+        for row in 0..4 {
+            for column in 0..4 {
+                assert!( approx_eq!(f32, product[[row, column]], identity[[row, column]], epsilon = 0.0001) );
+            }
+        }
+    }
+
+    #[test]
+    fn test_inverse_of_singular_matrix() {
+        // Arrange
+        let matrix_a = Matrix4x4::identity().scale(1.0, 0.0, 1.0);
+
+        // Act
+        let inverse_matrix = matrix_a.inverse();
+
+        // Assert
+        assert!(inverse_matrix.is_none());
+    }
+
+    #[test]
+    fn test_transform_point_and_vector() {
+        // Arrange
+        let matrix_a = Matrix4x4::identity().translate(Vector2::new(1.0, 2.0)).scale(2.0, 2.0, 2.0);
+
+        // Act
+        let point = matrix_a.transform_point(Vector3::new(1.0, 1.0, 1.0));
+        let vector = matrix_a.transform_vector(Vector3::new(1.0, 1.0, 1.0));
+
+        // Assert
+        assert_eq!(point.x, 3.0);
+        assert_eq!(point.y, 4.0);
+        assert_eq!(point.z, 2.0);
+        assert_eq!(vector.x, 2.0);
+        assert_eq!(vector.y, 2.0);
+        assert_eq!(vector.z, 2.0);
+    }
+
+    #[test]
+    fn test_mul_vector4() {
+        // Arrange
+        let matrix_a = Matrix4x4::new(
+            1.0, 2.0, 3.0, 4.0, 
+            5.0, 6.0, 7.0, 8.0, 
+            9.0, 10.0, 11.0, 12.0, 
+            13.0, 14.0, 15.0, 16.0);
+
+        // Act
+        let result = matrix_a.mul_vector4(Vector4::new(1.0, 0.0, 2.0, 1.0));
+
+        // Assert
+        assert_eq!(result.x, 11.0);
+        assert_eq!(result.y, 27.0);
+        assert_eq!(result.z, 43.0);
+        assert_eq!(result.w, 59.0);
+    }
+
+    #[test]
+    fn test_reflection() {
+        // Arrange
+        // Mirror across the plane y = 1
+        let plane = Plane::new(Vector3::new(0.0, 1.0, 0.0), -1.0);
+
+        // Act
+        let reflection_matrix = Matrix4x4::reflection(&plane);
+        let reflected_point = reflection_matrix.transform_point(Vector3::new(2.0, 3.0, -1.0));
+
+        // Assert
+        assert!( approx_eq!(f32, reflected_point.x, 2.0, epsilon = 0.0001) );
+        assert!( approx_eq!(f32, reflected_point.y, -1.0, epsilon = 0.0001) );
+        assert!( approx_eq!(f32, reflected_point.z, -1.0, epsilon = 0.0001) );
+    }
+
+    #[test]
+    fn test_lerp() {
+        // Arrange
+        let matrix_a = Matrix4x4::identity();
+        let matrix_b = Matrix4x4::identity().scale(3.0, 5.0, 1.0);
+
+        // Act
+        let interpolated_matrix = matrix_a.lerp(matrix_b, 0.5);
+
+        // Assert
+        assert_eq!(interpolated_matrix[[0, 0]], 2.0);
+        assert_eq!(interpolated_matrix[[1, 1]], 3.0);
+        assert_eq!(interpolated_matrix[[2, 2]], 1.0);
+        assert_eq!(interpolated_matrix[[0, 1]], 0.0);
+    }
+
     #[test]
     fn test_pretty_print() {
         // Arrange
@@ -236,6 +329,48 @@ mod tests {
         println!("{:?}", matrix_a);
     }
 
+    #[test]
+    fn test_display() {
+        // Arrange
+        let matrix_a = Matrix4x4::identity().translate(Vector2::new(-12.5, 3.0));
+
+        // Act
+        let display = format!("{}", matrix_a);
+        let display_with_precision = format!("{:.2}", matrix_a);
+        let display_with_width = format!("{:6.1}", matrix_a);
+
+        // Assert
+        assert_eq!(display, "[1, 0, 0, -12.5]\n[0, 1, 0,     3]\n[0, 0, 1,     0]\n[0, 0, 0,     1]");
+        assert_eq!(display_with_precision.lines().nth(1), Some("[0.00, 1.00, 0.00,   3.00]"));
+        assert_eq!(display_with_width.lines().next(), Some("[   1.0,    0.0,    0.0,  -12.5]"));
+    }
+
+    #[test]
+    fn test_compact_display() {
+        // Arrange
+        let matrix_a = Matrix4x4::identity();
+
+        // Act
+        let display = format!("{:#}", matrix_a);
+
+        // Assert
+        assert_eq!(display, "[[1, 0, 0, 0], [0, 1, 0, 0], [0, 0, 1, 0], [0, 0, 0, 1]]");
+    }
+
+    #[test]
+    fn test_debug() {
+        // Arrange
+        let matrix_a = Matrix4x4::identity().scale(2.5, 1.0, 1.0);
+
+        // Act
+        let debug = format!("{:?}", matrix_a);
+        let pretty_debug = format!("{:#?}", matrix_a);
+
+        // Assert
+        assert_eq!(debug, "Matrix4x4 [[2.5, 0.0, 0.0, 0.0], [0.0, 1.0, 0.0, 0.0], [0.0, 0.0, 1.0, 0.0], [0.0, 0.0, 0.0, 1.0]]");
+        assert_eq!(pretty_debug, "Matrix4x4 [\n    [2.5, 0.0, 0.0, 0.0],\n    [0.0, 1.0, 0.0, 0.0],\n    [0.0, 0.0, 1.0, 0.0],\n    [0.0, 0.0, 0.0, 1.0],\n]");
+    }
+
     #[test]
     fn test_indexing() {
         // Arrange
@@ -287,4 +422,91 @@ mod tests {
         assert_eq!(m23, 12.0);
         assert_eq!(m33, 16.0);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_from_str() {
+        // Arrange
+        let text = "1, 2, 3, 4
+                    5, 6, 7, 8
+                    9, 10, 11, 12
+                    13, 14, 15, 16";
+
+        // Act
+        let parsed_matrix: Matrix4x4 = text.parse().unwrap();
+
+        // Assert
+        assert_eq!(parsed_matrix[[0, 1]], 2.0);
+        assert_eq!(parsed_matrix[[1, 0]], 5.0);
+        assert_eq!(parsed_matrix[[3, 3]], 16.0);
+    }
+
+    #[test]
+    fn test_display_round_trip() {
+        // Arrange
+        let matrix_a = Matrix4x4::identity()
+            .translate(Vector2::new(0.1, -250.75))
+            .rotate(0.0, 0.0, 1.3)
+            .scale(3.0, 1e-5, 2.0);
+
+        // Act
+        let parsed_matrix: Matrix4x4 = matrix_a.to_string().parse().unwrap();
+        let parsed_compact_matrix: Matrix4x4 = format!("{:#}", matrix_a).parse().unwrap();
+
+        // Assert
+        assert!(parsed_matrix == matrix_a);
+        assert!(parsed_compact_matrix == matrix_a);
+    }
+
+    #[test]
+    fn test_from_str_error() {
+        // Act
+        let error = "[1, 0, 0, 0], [0, 1, 0, 0], [0, 0, 1, 0], [0, 0, 0]".parse::<Matrix4x4>().unwrap_err();
+
+        // Assert
+        assert_eq!(error.to_string(), "expected 16 components but found only 15 at bytes 51..51");
+    }
+
+    #[test]
+    fn test_memory_layout() {
+        // Act & Assert
+        assert_eq!(std::mem::size_of::<Matrix4x4>(), 64);
+        assert_eq!(std::mem::align_of::<Matrix4x4>(), 4);
+    }
+
+    #[test]
+    fn test_array_conversions() {
+        // Arrange
+        let matrix_a = Matrix4x4::identity().translate(Vector2::new(5.0, 7.0));
+        let rows = [
+            1.0, 0.0, 0.0, 5.0,
+            0.0, 1.0, 0.0, 7.0,
+            0.0, 0.0, 1.0, 0.0,
+            0.0, 0.0, 0.0, 1.0];
+
+        // Act
+        let columns = matrix_a.to_cols_array();
+        let columns_2d = matrix_a.to_cols_array_2d();
+
+        // Assert
+        assert_eq!(&columns[12..], &[5.0, 7.0, 0.0, 1.0]);
+        assert_eq!(columns_2d[3], [5.0, 7.0, 0.0, 1.0]);
+        assert_eq!(matrix_a.to_rows_array(), rows);
+        assert!(Matrix4x4::from_rows_array(rows) == matrix_a);
+        assert!(Matrix4x4::from_cols_array(columns) == matrix_a);
+        assert!(Matrix4x4::from_cols_array_2d(columns_2d) == matrix_a);
+    }
+
+    #[test]
+    fn test_flat_slice_view() {
+        // Arrange
+        let matrices = [Matrix4x4::identity(), Matrix4x4::identity().scale(2.0, 2.0, 2.0)];
+
+        // Act
+        let elements = Matrix4x4::as_flat_slice(&matrices);
+
+        // Assert
+        assert_eq!(elements.len(), 32);
+        assert_eq!(elements[16], 2.0);
+        assert_eq!(&elements[..16], matrices[0].as_cols_array());
+    }
+}
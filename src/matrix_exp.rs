@@ -0,0 +1,349 @@
+use crate::vector3::Vector3;
+use crate::float_trait::Float;
+use crate::matrix::Matrix;
+
+// The matrix exponential and logarithm. They connect rotations to their "Lie algebra": every rotation is the exponential of a skew-symmetric matrix,
+// which holds an axis scaled by an angle. Rotations cannot be added or scaled, but these angle-axis vectors can,
+// so interpolating and averaging rotations is done by taking logarithms, working on the vectors, and taking the exponential of the result.
+
+// The largest sum of absolute values in a row. It bounds how much the matrix can stretch a vector, which decides how many terms the series need.
+fn infinity_norm<T: Float, const N: usize>(matrix: &Matrix<T, N, N>) -> T {
+    let mut norm = T::zero();
+
+    for row in 0..N {
+        let mut sum = T::zero();
+
+        for column in 0..N {
+            sum = sum + matrix[[row, column]].abs();
+        }
+
+        if sum > norm {
+            norm = sum;
+        }
+    }
+
+    norm
+}
+
+// The principal square root, the one whose eigenvalues have positive real parts, using the Denman-Beavers iteration.
+// Returns None if the iteration does not converge, which happens when the matrix is singular or has negative real eigenvalues.
+fn square_root<T: Float, const N: usize>(matrix: &Matrix<T, N, N>) -> Option<Matrix<T, N, N>> {
+    let half = T::one() / (T::one() + T::one());
+    let mut y = *matrix;
+    let mut z = Matrix::identity();
+    let mut converged = false;
+
+    for _iteration in 0..64 {
+        let next_y = (y + z.inverse()?).scalar_multiplication(half);
+        let next_z = (z + y.inverse()?).scalar_multiplication(half);
+        let change = infinity_norm(&(next_y - y));
+
+        y = next_y;
+        z = next_z;
+
+        // The iteration converges quadratically, so one more step after reaching the square root of epsilon gives full precision
+        if converged {
+            return Some(y);
+        }
+
+        converged = change <= infinity_norm(&y) * T::epsilon().sqrt();
+    }
+
+    None
+}
+
+impl<T: Float, const N: usize> Matrix<T, N, N> {
+    // e^A, defined by the same series as for numbers: I + A + A^2/2! + A^3/3! + ...
+    // The series converges slowly for large matrices, so A is first halved until it is small, and the result squared as many times,
+    // since e^A = (e^(A/2))^2. This is called scaling and squaring.
+    pub fn exp(&self) -> Matrix<T, N, N> {
+        let half = T::one() / (T::one() + T::one());
+        let mut scaled = *self;
+        let mut norm = infinity_norm(self);
+        let mut squarings = 0;
+
+        while norm > half && squarings < 64 {
+            scaled = scaled.scalar_multiplication(half);
+            norm = norm * half;
+            squarings += 1;
+        }
+
+        // With a norm of at most 1/2, the terms after the 16th are below the precision of f64
+        let mut result = Matrix::identity();
+        let mut term = Matrix::identity();
+
+        for k in 1..=16 {
+            term = term.mul(scaled).scalar_multiplication(T::one() / T::from_f64(k as f64));
+            result = result + term;
+        }
+
+        for _squaring in 0..squarings {
+            result = result.mul(result);
+        }
+
+        result
+    }
+
+    // The principal logarithm, the inverse of exp, using inverse scaling and squaring:
+    // square roots are taken until the matrix is close to the identity, where the series log(I + X) = X - X^2/2 + X^3/3 - ... converges quickly,
+    // and the result is doubled once for every square root, since log(A) = 2 log(A^(1/2)).
+    // Returns None if the matrix has no real logarithm, for example because it is singular or has negative eigenvalues.
+    // For rotations, use rotation_log instead, which is both faster and also handles rotations by 180 degrees.
+    pub fn log(&self) -> Option<Matrix<T, N, N>> {
+        let identity = Matrix::<T, N, N>::identity();
+        let quarter = T::from_f64(0.25);
+        let mut root = *self;
+        let mut scale = T::one();
+
+        for _square_root in 0..32 {
+            if infinity_norm(&(root - identity)) <= quarter {
+                break;
+            }
+
+            root = square_root(&root)?;
+            scale = scale + scale;
+        }
+
+        let x = root - identity;
+        // Written this way around so that a matrix of NaNs also fails
+        let is_close_to_identity = infinity_norm(&x) <= quarter;
+
+        if !is_close_to_identity {
+            return None;
+        }
+
+        let mut result = x;
+        let mut power = x;
+
+        // With a norm of at most 1/4, the terms after the 24th are below the precision of f64
+        for k in 2..=24 {
+            power = power.mul(x);
+            let term = power.scalar_multiplication(T::one() / T::from_f64(k as f64));
+            result = if k % 2 == 0 { result - term } else { result + term };
+        }
+
+        Some(result.scalar_multiplication(scale))
+    }
+}
+
+// The skew-symmetric matrix K for which K v equals the cross product of "vector" and v
+fn skew_symmetric<T: Float>(vector: Vector3<T>) -> Matrix<T, 3, 3> {
+    Matrix::from_rows([
+        [T::zero(), -vector.z, vector.y],
+        [vector.z, T::zero(), -vector.x],
+        [-vector.y, vector.x, T::zero()]])
+}
+
+impl<T: Float> Matrix<T, 3, 3> {
+    // The rotation around the axis of "vector", by an angle in radians equal to its length, using the Rodrigues formula.
+    // This is the exponential of the skew-symmetric matrix of the vector, but much cheaper than the general exp.
+    pub fn rotation_exp(vector: Vector3<T>) -> Matrix<T, 3, 3> {
+        let angle_squared = vector.dot_product(vector);
+        let angle = angle_squared.sqrt();
+        let k = skew_symmetric(vector);
+
+        // sin(angle) / angle and (1 - cos(angle)) / angle^2 divide by zero for tiny angles, so their Taylor series are used instead
+        let (a, b) = if angle_squared <= T::epsilon() {
+            (T::one() - angle_squared / T::from_f64(6.0), T::from_f64(0.5) - angle_squared / T::from_f64(24.0))
+        } else {
+            (angle.sin() / angle, (T::one() - angle.cos()) / angle_squared)
+        };
+
+        Matrix::identity() + k.scalar_multiplication(a) + k.mul(k).scalar_multiplication(b)
+    }
+
+    // The inverse of rotation_exp: the axis of the rotation scaled by its angle in radians, which is between 0 and pi.
+    // The matrix is expected to be a rotation, with orthonormal columns and a determinant of +1.
+    pub fn rotation_log(&self) -> Vector3<T> {
+        let half = T::from_f64(0.5);
+        let trace = self[[0, 0]] + self[[1, 1]] + self[[2, 2]];
+        // Twice the sine of the angle times the axis
+        let w = Vector3::new(self[[2, 1]] - self[[1, 2]], self[[0, 2]] - self[[2, 0]], self[[1, 0]] - self[[0, 1]]);
+
+        let cos_angle = (trace - T::one()) * half;
+        let sin_angle = w.length() * half;
+        let angle = sin_angle.atan2(cos_angle);
+
+        if cos_angle >= T::zero() {
+            // angle / sin(angle) is close to 1 + angle^2 / 6 for tiny angles, which avoids dividing zero by zero
+            let factor = if sin_angle <= T::epsilon() {
+                half * (T::one() + angle * angle / T::from_f64(6.0))
+            } else {
+                angle / (sin_angle + sin_angle)
+            };
+
+            return w.scalar_multiplication(factor);
+        }
+
+        // Close to 180 degrees, the sine is too small to recover the axis from w. Instead, the symmetric part of the matrix is
+        // cos(angle) I + (1 - cos(angle)) a a^T, from which the axis a is read off its largest column, and w only decides the sign.
+        let outer = |row: usize, column: usize| {
+            let identity = if row == column { cos_angle } else { T::zero() };
+            ((self[[row, column]] + self[[column, row]]) * half - identity) / (T::one() - cos_angle)
+        };
+
+        let mut largest = 0;
+
+        for index in 1..3 {
+            if outer(index, index) > outer(largest, largest) {
+                largest = index;
+            }
+        }
+
+        let length = outer(largest, largest).sqrt();
+        let mut axis = Vector3::new(outer(0, largest), outer(1, largest), outer(2, largest)).scalar_multiplication(T::one() / length);
+
+        if axis.dot_product(w) < T::zero() {
+            axis = axis.scalar_multiplication(-T::one());
+        }
+
+        axis.scalar_multiplication(angle)
+    }
+
+    // Interpolates between two rotations along the shortest arc, at a constant angular speed.
+    // Unlike interpolating the elements, every result is a rotation. This is the matrix equivalent of quaternion slerp.
+    pub fn rotation_interpolate(&self, other: Matrix<T, 3, 3>, t: T) -> Matrix<T, 3, 3> {
+        let difference = self.transpose().mul(other).rotation_log();
+
+        self.mul(Matrix::rotation_exp(difference.scalar_multiplication(t)))
+    }
+}
+
+// The rotation closest to all the given rotations, in the sense that it minimizes the sum of the squared angles to them (the Karcher mean).
+// Starting from the first rotation, the angle-axis offsets to all rotations are averaged, and the estimate moved by that average until it stops moving.
+// Averaging the elements and orthonormalizing the result is cheaper, but is biased when the rotations are far apart.
+// Returns None if there are no rotations.
+pub fn average_rotations<T: Float>(rotations: &[Matrix<T, 3, 3>]) -> Option<Matrix<T, 3, 3>> {
+    let mut mean = *rotations.first()?;
+    let mut count = T::zero();
+
+    for _rotation in rotations {
+        count = count + T::one();
+    }
+
+    for _iteration in 0..32 {
+        let mut offset = Vector3::new(T::zero(), T::zero(), T::zero());
+
+        for rotation in rotations {
+            offset = offset + mean.transpose().mul(*rotation).rotation_log();
+        }
+
+        offset = offset.scalar_multiplication(T::one() / count);
+        mean = mean.mul(Matrix::rotation_exp(offset));
+
+        if offset.length() <= T::epsilon() * T::from_f64(16.0) {
+            break;
+        }
+    }
+
+    Some(mean)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use float_cmp::approx_eq;
+
+    fn assert_matrices_equal<const N: usize>(a: &Matrix<f64, N, N>, b: &Matrix<f64, N, N>) {
+        for row in 0..N {
+            for column in 0..N {
+                assert!( approx_eq!(f64, a[[row, column]], b[[row, column]], epsilon = 0.0001) );
+            }
+        }
+    }
+
+    #[test]
+    fn test_exp_and_log() {
+        // Arrange
+        let matrix = Matrix::<f64, 2, 2>::from_rows([[1.0, 2.0], [0.0, 1.0]]);
+
+        // Act
+        let exponential = matrix.exp();
+        let logarithm = exponential.log().unwrap();
+
+        // Assert
+        // For this matrix, e^A = e [[1, 2], [0, 1]]
+        let e = core::f64::consts::E;
+        assert_matrices_equal(&exponential, &Matrix::from_rows([[e, 2.0 * e], [0.0, e]]));
+        assert_matrices_equal(&logarithm, &matrix);
+    }
+
+    #[test]
+    fn test_log_of_matrix_without_real_logarithm() {
+        // Arrange
+        let matrix = Matrix::<f64, 2, 2>::from_rows([[-1.0, 0.0], [0.0, 2.0]]);
+
+        // Act & Assert
+        assert!(matrix.log().is_none());
+        assert!(Matrix::<f64, 2, 2>::from_rows([[0.0, 0.0], [0.0, 1.0]]).log().is_none());
+    }
+
+    #[test]
+    fn test_rotation_exp_matches_exp() {
+        // Arrange
+        let vector = Vector3::new(0.3, -1.2, 2.0);
+
+        // Act
+        let rotation = Matrix::rotation_exp(vector);
+        let general = skew_symmetric(vector).exp();
+
+        // Assert
+        assert_matrices_equal(&rotation, &general);
+        assert!( approx_eq!(f64, rotation.determinant(), 1.0, epsilon = 0.0001) );
+    }
+
+    #[test]
+    fn test_rotation_log() {
+        // Arrange
+        let vectors = [
+            Vector3::new(0.0, 0.0, 0.0),
+            Vector3::new(1e-9, 0.0, 0.0),
+            Vector3::new(0.5, 0.5, -0.2),
+            Vector3::new(0.0, 2.5, 1.0),
+            Vector3::new(0.0, 0.0, core::f64::consts::PI),
+            Vector3::new(0.0, 3.1, 0.0)];
+
+        for vector in vectors.iter() {
+            // Act
+            let logarithm = Matrix::rotation_exp(*vector).rotation_log();
+
+            // Assert
+            assert!( approx_eq!(f64, (logarithm - *vector).length(), 0.0, epsilon = 0.0001) );
+        }
+    }
+
+    #[test]
+    fn test_rotation_interpolate() {
+        // Arrange
+        let a = Matrix::rotation_exp(Vector3::new(0.0, 0.0, 0.2));
+        let b = Matrix::rotation_exp(Vector3::new(0.0, 0.0, 1.4));
+
+        // Act
+        let halfway = a.rotation_interpolate(b, 0.5);
+
+        // Assert
+        assert_matrices_equal(&halfway, &Matrix::rotation_exp(Vector3::new(0.0, 0.0, 0.8)));
+        assert_matrices_equal(&a.rotation_interpolate(b, 0.0), &a);
+        assert_matrices_equal(&a.rotation_interpolate(b, 1.0), &b);
+    }
+
+    #[test]
+    fn test_average_rotations() {
+        // Arrange
+        let rotations = [
+            Matrix::rotation_exp(Vector3::new(0.1, 0.0, 0.5)),
+            Matrix::rotation_exp(Vector3::new(-0.1, 0.0, 0.5)),
+            Matrix::rotation_exp(Vector3::new(0.0, 0.2, 0.5)),
+            Matrix::rotation_exp(Vector3::new(0.0, -0.2, 0.5))];
+
+        // Act
+        let average = average_rotations(&rotations).unwrap();
+
+        // Assert
+        let axis_angle = average.rotation_log();
+        assert!( approx_eq!(f64, axis_angle.x, 0.0, epsilon = 0.0001) );
+        assert!( approx_eq!(f64, axis_angle.y, 0.0, epsilon = 0.0001) );
+        assert!( approx_eq!(f64, axis_angle.z, 0.5, epsilon = 0.01) );
+        assert!(average_rotations::<f64>(&[]).is_none());
+    }
+}
@@ -0,0 +1,279 @@
+use alloc::vec::Vec;
+use core::fmt;
+use core::cell::Cell;
+use core::ops::{Deref, DerefMut};
+
+use crate::vector2::Vector2;
+use crate::matrix4x4::Matrix4x4;
+
+// The number of matrices a stack holds by default, which is the smallest modelview stack depth the OpenGL specification allows
+pub const DEFAULT_MAX_DEPTH: usize = 32;
+
+// A stack of transformations, like the matrix stack of old fixed-function OpenGL.
+// Drawing code pushes a copy of the current matrix, transforms it for a nested element, and pops it again when the element is done,
+// so the parent continues with its own matrix. The stack always holds at least one matrix, which starts out as the identity.
+// The maximum depth catches a push without a matching pop, which would otherwise grow the stack forever.
+pub struct MatrixStack {
+    matrices: Vec<Matrix4x4>,
+    max_depth: usize,
+    // The inverse of the top matrix, computed when first asked for and forgotten whenever the top changes.
+    // Hit testing asks for it once per element, while the top changes far less often.
+    inverse: Cell<Option<Matrix4x4>>
+}
+
+// The error returned by push and pop when they would break the limits of the stack
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum MatrixStackError {
+    // Pushing would make the stack hold more than max_depth matrices
+    Overflow { max_depth: usize },
+    // Popping would remove the last matrix
+    Underflow
+}
+
+impl fmt::Display for MatrixStackError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MatrixStackError::Overflow { max_depth } => write!(f, "the matrix stack is full, it can hold at most {} matrices", max_depth),
+            MatrixStackError::Underflow => write!(f, "cannot pop the last matrix of the matrix stack")
+        }
+    }
+}
+
+impl core::error::Error for MatrixStackError {}
+
+impl MatrixStack {
+    pub fn new() -> MatrixStack {
+        MatrixStack::with_max_depth(DEFAULT_MAX_DEPTH)
+    }
+
+    // Panics if max_depth is 0, since the stack always holds at least one matrix
+    pub fn with_max_depth(max_depth: usize) -> MatrixStack {
+        if max_depth == 0 {
+            panic!("A matrix stack must be able to hold at least one matrix!");
+        }
+
+        let mut matrices = Vec::with_capacity(max_depth.min(DEFAULT_MAX_DEPTH));
+        matrices.push(Matrix4x4::identity());
+
+        MatrixStack {
+            matrices,
+            max_depth,
+            inverse: Cell::new(None)
+        }
+    }
+
+    // The number of matrices on the stack, which is 1 when nothing has been pushed
+    pub fn depth(&self) -> usize {
+        self.matrices.len()
+    }
+
+    pub fn max_depth(&self) -> usize {
+        self.max_depth
+    }
+
+    pub fn top(&self) -> Matrix4x4 {
+        self.matrices[self.matrices.len() - 1]
+    }
+
+    // The inverse of the top matrix, for moving points such as the mouse position back into the space of the current element.
+    // Returns None if the top matrix is singular, for example after scaling by zero.
+    pub fn inverse(&self) -> Option<Matrix4x4> {
+        if let Some(inverse) = self.inverse.get() {
+            return Some(inverse);
+        }
+
+        // A singular top is not cached, so it is found to be singular again on every call
+        let inverse = self.top().inverse();
+        self.inverse.set(inverse);
+
+        inverse
+    }
+
+    // Pushes a copy of the top matrix, so that it can be transformed and restored later with pop
+    pub fn push(&mut self) -> Result<(), MatrixStackError> {
+        if self.matrices.len() >= self.max_depth {
+            return Err(MatrixStackError::Overflow { max_depth: self.max_depth });
+        }
+
+        self.matrices.push(self.top());
+
+        Ok(())
+    }
+
+    // Removes the top matrix and returns it, making the matrix below it the top again
+    pub fn pop(&mut self) -> Result<Matrix4x4, MatrixStackError> {
+        if self.matrices.len() == 1 {
+            return Err(MatrixStackError::Underflow);
+        }
+
+        self.inverse.set(None);
+
+        Ok(self.matrices.pop().unwrap())
+    }
+
+    // Pushes a matrix and returns a guard that pops it again when dropped, so a nested element cannot forget to restore its parent's matrix,
+    // even when returning early. The guard can be used like the stack itself.
+    pub fn push_scope(&mut self) -> Result<MatrixStackGuard<'_>, MatrixStackError> {
+        self.push()?;
+
+        Ok(MatrixStackGuard {
+            depth: self.depth(),
+            stack: self
+        })
+    }
+
+    pub fn load(&mut self, matrix: Matrix4x4) {
+        let top = self.matrices.len() - 1;
+        self.matrices[top] = matrix;
+        self.inverse.set(None);
+    }
+
+    pub fn load_identity(&mut self) {
+        self.load(Matrix4x4::identity());
+    }
+
+    // Multiplies the top matrix by "matrix" on the right, so that it is applied to points before the transformations already on the stack
+    pub fn multiply(&mut self, matrix: Matrix4x4) {
+        self.load(self.top().mul(matrix));
+    }
+
+    pub fn translate(&mut self, vector2: Vector2<f32>) {
+        self.load(self.top().translate(vector2));
+    }
+
+    pub fn rotate(&mut self, head: f32, pitch: f32, roll: f32) {
+        self.load(self.top().rotate(head, pitch, roll));
+    }
+
+    pub fn scale(&mut self, x: f32, y: f32, z: f32) {
+        self.load(self.top().scale(x, y, z));
+    }
+}
+
+impl Default for MatrixStack {
+    fn default() -> MatrixStack {
+        MatrixStack::new()
+    }
+}
+
+// Returned by MatrixStack::push_scope. When dropped, the stack is restored to the depth it had before the push,
+// which also pops anything that was pushed through the guard and not popped.
+pub struct MatrixStackGuard<'a> {
+    stack: &'a mut MatrixStack,
+    depth: usize
+}
+
+impl Deref for MatrixStackGuard<'_> {
+    type Target = MatrixStack;
+
+    fn deref(&self) -> &MatrixStack {
+        self.stack
+    }
+}
+
+impl DerefMut for MatrixStackGuard<'_> {
+    fn deref_mut(&mut self) -> &mut MatrixStack {
+        self.stack
+    }
+}
+
+impl Drop for MatrixStackGuard<'_> {
+    fn drop(&mut self) {
+        // Popping through the guard may already have removed the pushed matrix, in which case there is nothing left to restore
+        if self.stack.matrices.len() >= self.depth {
+            self.stack.matrices.truncate(self.depth - 1);
+            self.stack.inverse.set(None);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vector3::Vector3;
+    use float_cmp::approx_eq;
+
+    fn assert_vectors_equal(a: Vector3<f32>, b: Vector3<f32>) {
+        assert!( approx_eq!(f32, (a - b).length(), 0.0, epsilon = 0.0001) );
+    }
+
+    #[test]
+    fn test_push_and_pop() {
+        // Arrange
+        let mut stack = MatrixStack::new();
+        stack.translate(Vector2::new(10.0, 20.0));
+        let parent = stack.top();
+
+        // Act
+        stack.push().unwrap();
+        stack.scale(2.0, 2.0, 1.0);
+        let child = stack.top();
+        let popped = stack.pop().unwrap();
+
+        // Assert
+        assert_eq!(popped, child);
+        assert_eq!(stack.top(), parent);
+        assert_eq!(child, Matrix4x4::identity().translate(Vector2::new(10.0, 20.0)).scale(2.0, 2.0, 1.0));
+        assert_eq!(stack.pop(), Err(MatrixStackError::Underflow));
+    }
+
+    #[test]
+    fn test_max_depth() {
+        // Arrange
+        let mut stack = MatrixStack::with_max_depth(2);
+
+        // Act
+        let first = stack.push();
+        let second = stack.push();
+
+        // Assert
+        assert_eq!(first, Ok(()));
+        assert_eq!(second, Err(MatrixStackError::Overflow { max_depth: 2 }));
+        assert_eq!(stack.depth(), 2);
+        assert_eq!(format!("{}", second.unwrap_err()), "the matrix stack is full, it can hold at most 2 matrices");
+    }
+
+    #[test]
+    fn test_push_scope() {
+        // Arrange
+        let mut stack = MatrixStack::new();
+        stack.translate(Vector2::new(1.0, 2.0));
+        let parent = stack.top();
+
+        // Act
+        {
+            let mut scope = stack.push_scope().unwrap();
+            scope.rotate(0.0, 0.0, 0.5);
+
+            // A push without a pop inside the scope is undone as well
+            scope.push().unwrap();
+            scope.load_identity();
+
+            assert_eq!(scope.depth(), 3);
+        }
+
+        // Assert
+        assert_eq!(stack.depth(), 1);
+        assert_eq!(stack.top(), parent);
+    }
+
+    #[test]
+    fn test_cached_inverse() {
+        // Arrange
+        let mut stack = MatrixStack::new();
+        stack.translate(Vector2::new(3.0, 4.0));
+        stack.scale(2.0, 2.0, 2.0);
+        let point = Vector3::new(1.0, 1.0, 0.0);
+
+        // Act
+        let inverse = stack.inverse().unwrap();
+        stack.scale(0.0, 1.0, 1.0);
+        let singular_inverse = stack.inverse();
+        stack.load_identity();
+
+        // Assert
+        assert_vectors_equal(inverse.transform_point(Vector3::new(5.0, 6.0, 0.0)), point);
+        assert!(singular_inverse.is_none());
+        assert_eq!(stack.inverse(), Some(Matrix4x4::identity()));
+    }
+}
@@ -0,0 +1,124 @@
+use core::ops::{Add, Sub, Mul};
+
+use crate::vector2::Vector2;
+use crate::vector3::Vector3;
+use crate::vector4::Vector4;
+use crate::matrix4x4::Matrix4x4;
+
+// mint only defines plain types for passing math values between crates, so the conversions are direct copies of the components.
+
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy> From<mint::Vector2<T>> for Vector2<T> {
+    fn from(vector: mint::Vector2<T>) -> Vector2<T> {
+        Vector2::new(vector.x, vector.y)
+    }
+}
+
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy> From<Vector2<T>> for mint::Vector2<T> {
+    fn from(vector: Vector2<T>) -> mint::Vector2<T> {
+        mint::Vector2 { x: vector.x, y: vector.y }
+    }
+}
+
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy> From<mint::Vector3<T>> for Vector3<T> {
+    fn from(vector: mint::Vector3<T>) -> Vector3<T> {
+        Vector3::new(vector.x, vector.y, vector.z)
+    }
+}
+
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy> From<Vector3<T>> for mint::Vector3<T> {
+    fn from(vector: Vector3<T>) -> mint::Vector3<T> {
+        mint::Vector3 { x: vector.x, y: vector.y, z: vector.z }
+    }
+}
+
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy> From<mint::Vector4<T>> for Vector4<T> {
+    fn from(vector: mint::Vector4<T>) -> Vector4<T> {
+        Vector4::new(vector.x, vector.y, vector.z, vector.w)
+    }
+}
+
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy> From<Vector4<T>> for mint::Vector4<T> {
+    fn from(vector: Vector4<T>) -> mint::Vector4<T> {
+        mint::Vector4 { x: vector.x, y: vector.y, z: vector.z, w: vector.w }
+    }
+}
+
+// A mint::ColumnMatrix4 stores the four columns as vectors, matching the column-major storage of Matrix4x4
+impl From<mint::ColumnMatrix4<f32>> for Matrix4x4 {
+    fn from(matrix: mint::ColumnMatrix4<f32>) -> Matrix4x4 {
+        let (x, y, z, w) = (matrix.x, matrix.y, matrix.z, matrix.w);
+
+        Matrix4x4::new(
+            x.x, y.x, z.x, w.x,
+            x.y, y.y, z.y, w.y,
+            x.z, y.z, z.z, w.z,
+            x.w, y.w, z.w, w.w)
+    }
+}
+
+impl From<Matrix4x4> for mint::ColumnMatrix4<f32> {
+    fn from(matrix: Matrix4x4) -> mint::ColumnMatrix4<f32> {
+        let column = |c: usize| mint::Vector4 { x: matrix[[0, c]], y: matrix[[1, c]], z: matrix[[2, c]], w: matrix[[3, c]] };
+
+        mint::ColumnMatrix4 { x: column(0), y: column(1), z: column(2), w: column(3) }
+    }
+}
+
+// A mint::RowMatrix4 stores the four rows as vectors instead
+impl From<mint::RowMatrix4<f32>> for Matrix4x4 {
+    fn from(matrix: mint::RowMatrix4<f32>) -> Matrix4x4 {
+        let (x, y, z, w) = (matrix.x, matrix.y, matrix.z, matrix.w);
+
+        Matrix4x4::new(
+            x.x, x.y, x.z, x.w,
+            y.x, y.y, y.z, y.w,
+            z.x, z.y, z.z, z.w,
+            w.x, w.y, w.z, w.w)
+    }
+}
+
+impl From<Matrix4x4> for mint::RowMatrix4<f32> {
+    fn from(matrix: Matrix4x4) -> mint::RowMatrix4<f32> {
+        let row = |r: usize| mint::Vector4 { x: matrix[[r, 0]], y: matrix[[r, 1]], z: matrix[[r, 2]], w: matrix[[r, 3]] };
+
+        mint::RowMatrix4 { x: row(0), y: row(1), z: row(2), w: row(3) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vector_round_trip() {
+        // Arrange
+        let vector_a = Vector3::<i32>::new(1, 2, 3);
+
+        // Act
+        let mint_vector: mint::Vector3<i32> = vector_a.into();
+        let converted_vector: Vector3<i32> = mint_vector.into();
+
+        // Assert
+        assert_eq!(mint_vector.z, 3);
+        assert_eq!(converted_vector, vector_a);
+    }
+
+    #[test]
+    fn test_matrix_round_trip() {
+        // Arrange
+        let matrix_a = Matrix4x4::identity().translate(Vector2::new(5.0, 7.0));
+
+        // Act
+        let column_matrix: mint::ColumnMatrix4<f32> = matrix_a.into();
+        let row_matrix: mint::RowMatrix4<f32> = matrix_a.into();
+
+        // Assert
+        // The translation is the last column, which is the "w" column, and the last element of the first two rows
+        assert_eq!(column_matrix.w.x, 5.0);
+        assert_eq!(column_matrix.w.y, 7.0);
+        assert_eq!(row_matrix.x.w, 5.0);
+        assert_eq!(row_matrix.y.w, 7.0);
+        assert!(Matrix4x4::from(column_matrix) == matrix_a);
+        assert!(Matrix4x4::from(row_matrix) == matrix_a);
+    }
+}
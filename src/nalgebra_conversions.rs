@@ -0,0 +1,56 @@
+use core::ops::{Add, Sub, Mul};
+
+use crate::matrix::Matrix;
+
+// nalgebra requires its components to implement nalgebra::Scalar, which all the primitive number types do.
+
+// nalgebra stores matrices in column-major order too, but its indexing is (row, column) like ours,
+// so going through the indices keeps the conversion independent of storage order. This covers Matrix4x4 and every other size,
+// as well as Vector2, Vector3 and Vector4, which are nalgebra::Vector2, nalgebra::Vector3 and nalgebra::Vector4 on the other side.
+impl<T, const R: usize, const C: usize> From<nalgebra::SMatrix<T, R, C>> for Matrix<T, R, C> where T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy + nalgebra::Scalar {
+    fn from(matrix: nalgebra::SMatrix<T, R, C>) -> Matrix<T, R, C> {
+        Matrix::from_fn(|row, column| matrix[(row, column)])
+    }
+}
+
+impl<T, const R: usize, const C: usize> From<Matrix<T, R, C>> for nalgebra::SMatrix<T, R, C> where T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy + nalgebra::Scalar {
+    fn from(matrix: Matrix<T, R, C>) -> nalgebra::SMatrix<T, R, C> {
+        nalgebra::SMatrix::from_fn(|row, column| matrix[[row, column]])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::vector2::Vector2;
+    use crate::matrix4x4::Matrix4x4;
+
+    #[test]
+    fn test_vector_round_trip() {
+        // Arrange
+        let vector_a = Vector2::<f64>::new(1.0, 2.0);
+
+        // Act
+        let nalgebra_vector: nalgebra::Vector2<f64> = vector_a.into();
+        let converted_vector: Vector2<f64> = nalgebra_vector.into();
+
+        // Assert
+        assert_eq!(nalgebra_vector, nalgebra::Vector2::new(1.0, 2.0));
+        assert_eq!(converted_vector, vector_a);
+    }
+
+    #[test]
+    fn test_matrix_round_trip() {
+        // Arrange
+        let matrix_a = Matrix4x4::identity().translate(Vector2::new(5.0, 7.0));
+
+        // Act
+        let nalgebra_matrix: nalgebra::Matrix4<f32> = matrix_a.into();
+        let converted_matrix: Matrix4x4 = nalgebra_matrix.into();
+
+        // Assert
+        assert_eq!(nalgebra_matrix, nalgebra::Matrix4::new_translation(&nalgebra::Vector3::new(5.0, 7.0, 0.0)));
+        // The translation is stored in the last four elements in both crates
+        assert_eq!(nalgebra_matrix.as_slice()[12], 5.0);
+        assert!(converted_matrix == matrix_a);
+    }
+}
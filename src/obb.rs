@@ -0,0 +1,350 @@
+use core::fmt;
+
+use crate::vector3::Vector3;
+use crate::matrix::Matrix;
+use crate::matrix4x4::Matrix4x4;
+use crate::float_trait::Float;
+use crate::eigen::covariance;
+
+// Oriented bounding box. The rotation holds the box's local x, y and z axes in world space,
+// which are the columns of its rotation matrix. They are expected to be of unit length and perpendicular to each other.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Obb3<T> where T: Float {
+    pub center: Vector3<T>,
+    pub half_extents: Vector3<T>,
+    pub rotation: [Vector3<T>; 3]
+}
+
+impl<T: Float> Obb3<T> {
+    pub fn new(center: Vector3<T>, half_extents: Vector3<T>, rotation: [Vector3<T>; 3]) -> Obb3<T> {
+        Obb3 {
+            center,
+            half_extents,
+            rotation
+        }
+    }
+
+    // Fits a box to the points using principal component analysis.
+    // The axes of the box are the eigenvectors of the points' covariance matrix, which are the directions in which the points are most spread out.
+    // The extents are then found by projecting every point onto those axes. Returns None if there are no points.
+    pub fn from_points(points: &[Vector3<T>]) -> Option<Obb3<T>> {
+        let (mean, covariance) = covariance(points)?;
+        let eigen = covariance.symmetric_eigen();
+
+        // The eigenvectors always form a proper rotation, so the box gets a right-handed rotation
+        let axes = [eigen.eigenvector(0), eigen.eigenvector(1), eigen.eigenvector(2)];
+
+        let mut minimum = [T::zero(); 3];
+        let mut maximum = [T::zero(); 3];
+
+        for (index, point) in points.iter().enumerate() {
+            for axis in 0..3 {
+                let projection = axes[axis].dot_product(*point - mean);
+
+                if index == 0 || projection < minimum[axis] {
+                    minimum[axis] = projection;
+                }
+
+                if index == 0 || projection > maximum[axis] {
+                    maximum[axis] = projection;
+                }
+            }
+        }
+
+        let half = T::one() / (T::one() + T::one());
+
+        let mut center = mean;
+
+        for axis in 0..3 {
+            center = center + axes[axis].scalar_multiplication((minimum[axis] + maximum[axis]) * half);
+        }
+
+        let half_extents = Vector3::new(
+            (maximum[0] - minimum[0]) * half,
+            (maximum[1] - minimum[1]) * half,
+            (maximum[2] - minimum[2]) * half);
+
+        Some(Obb3::new(center, half_extents, axes))
+    }
+
+    pub fn contains_point(self, point: Vector3<T>) -> bool {
+        let offset = point - self.center;
+
+        offset.dot_product(self.rotation[0]).abs() <= self.half_extents.x &&
+        offset.dot_product(self.rotation[1]).abs() <= self.half_extents.y &&
+        offset.dot_product(self.rotation[2]).abs() <= self.half_extents.z
+    }
+
+    // Overlap test using the separating axis theorem. Two convex shapes are disjoint if, and only if, there is an axis onto which their projections don't overlap.
+    // For two boxes it is sufficient to test the 3 face normals of each box and the 9 cross products between their edges.
+    pub fn intersects(self, other: Obb3<T>) -> bool {
+        let a = [self.half_extents.x, self.half_extents.y, self.half_extents.z];
+        let b = [other.half_extents.x, other.half_extents.y, other.half_extents.z];
+
+        // The rotation of the other box expressed in this box's coordinate frame
+        let mut r = [[T::zero(); 3]; 3];
+        // A small epsilon counteracts arithmetic errors when two edges are parallel, and their cross product is close to zero
+        let mut absolute_r = [[T::zero(); 3]; 3];
+
+        for i in 0..3 {
+            for j in 0..3 {
+                r[i][j] = self.rotation[i].dot_product(other.rotation[j]);
+                absolute_r[i][j] = r[i][j].abs() + T::epsilon().sqrt();
+            }
+        }
+
+        let offset = other.center - self.center;
+        let t = [
+            offset.dot_product(self.rotation[0]),
+            offset.dot_product(self.rotation[1]),
+            offset.dot_product(self.rotation[2])
+        ];
+
+        // This box's face normals
+        for i in 0..3 {
+            let radius_a = a[i];
+            let radius_b = b[0] * absolute_r[i][0] + b[1] * absolute_r[i][1] + b[2] * absolute_r[i][2];
+
+            if t[i].abs() > radius_a + radius_b {
+                return false;
+            }
+        }
+
+        // The other box's face normals
+        for j in 0..3 {
+            let radius_a = a[0] * absolute_r[0][j] + a[1] * absolute_r[1][j] + a[2] * absolute_r[2][j];
+            let radius_b = b[j];
+            let distance = t[0] * r[0][j] + t[1] * r[1][j] + t[2] * r[2][j];
+
+            if distance.abs() > radius_a + radius_b {
+                return false;
+            }
+        }
+
+        // The cross products of every pair of edges, this box's axis i crossed with the other box's axis j
+        for i in 0..3 {
+            let (i1, i2) = ((i + 1) % 3, (i + 2) % 3);
+
+            for j in 0..3 {
+                let (j1, j2) = ((j + 1) % 3, (j + 2) % 3);
+
+                let radius_a = a[i1] * absolute_r[i2][j] + a[i2] * absolute_r[i1][j];
+                let radius_b = b[j1] * absolute_r[i][j2] + b[j2] * absolute_r[i][j1];
+                let distance = t[i2] * r[i1][j] - t[i1] * r[i2][j];
+
+                if distance.abs() > radius_a + radius_b {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+}
+
+impl Obb3<f32> {
+    // The transformed box is a parallelepiped, which is only a box again if the matrix keeps its axes perpendicular.
+    // A non-uniform scale of a rotated box, or any shear, skews it, so the new axes are taken from the rotation part of the polar decomposition
+    // of the transformed axes, which is the rotation closest to them. The half extents are then grown until the box encloses the whole parallelepiped.
+    // For rotations, translations and scales along the box's own axes the result is the exact transformed box.
+    pub fn transform(self, matrix: &Matrix4x4) -> Obb3<f32> {
+        let edges = [
+            matrix.transform_vector(self.rotation[0].scalar_multiplication(self.half_extents.x)),
+            matrix.transform_vector(self.rotation[1].scalar_multiplication(self.half_extents.y)),
+            matrix.transform_vector(self.rotation[2].scalar_multiplication(self.half_extents.z))];
+
+        let transformed_axes = Matrix::from_cols([
+            matrix.transform_vector(self.rotation[0]).to_array(),
+            matrix.transform_vector(self.rotation[1]).to_array(),
+            matrix.transform_vector(self.rotation[2]).to_array()]);
+        let axes = transformed_axes.polar_decomposition().rotation;
+        let rotation = [axes.column(0), axes.column(1), axes.column(2)];
+
+        // The parallelepiped reaches furthest along an axis at the corner where every edge points along it
+        let half_extent = |axis: Vector3<f32>| edges.iter().map(|edge| edge.dot_product(axis).abs()).sum();
+
+        Obb3 {
+            center: matrix.transform_point(self.center),
+            half_extents: Vector3::new(half_extent(rotation[0]), half_extent(rotation[1]), half_extent(rotation[2])),
+            rotation
+        }
+    }
+}
+
+impl<T: Float + fmt::Display> fmt::Display for Obb3<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "center ")?;
+        fmt::Display::fmt(&self.center, f)?;
+        write!(f, ", half extents ")?;
+        fmt::Display::fmt(&self.half_extents, f)?;
+        write!(f, ", rotation [")?;
+        fmt::Display::fmt(&self.rotation[0], f)?;
+        write!(f, ", ")?;
+        fmt::Display::fmt(&self.rotation[1], f)?;
+        write!(f, ", ")?;
+        fmt::Display::fmt(&self.rotation[2], f)?;
+        write!(f, "]")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vector2::Vector2;
+    use float_cmp::approx_eq;
+
+    fn axis_aligned_box(center: Vector3<f64>, half_extents: Vector3<f64>) -> Obb3<f64> {
+        Obb3::new(center, half_extents, [
+            Vector3::new(1.0, 0.0, 0.0),
+            Vector3::new(0.0, 1.0, 0.0),
+            Vector3::new(0.0, 0.0, 1.0)
+        ])
+    }
+
+    #[test]
+    fn test_from_points() {
+        // Arrange
+        // The corners of a 4x2x1 box rotated 45 degrees around the z axis
+        let diagonal = 0.5_f64.sqrt();
+        let axis_x = Vector3::new(diagonal, diagonal, 0.0);
+        let axis_y = Vector3::new(-diagonal, diagonal, 0.0);
+        let axis_z = Vector3::new(0.0, 0.0, 1.0);
+
+        let mut points = Vec::new();
+        for &x in &[-2.0, 2.0] {
+            for &y in &[-1.0, 1.0] {
+                for &z in &[-0.5, 0.5] {
+                    points.push(axis_x.scalar_multiplication(x) + axis_y.scalar_multiplication(y) + axis_z.scalar_multiplication(z));
+                }
+            }
+        }
+
+        // Act
+        let obb = Obb3::from_points(&points).unwrap();
+
+        // Assert
+        assert!( approx_eq!(f64, obb.half_extents.x, 2.0, epsilon = 0.0001) );
+        assert!( approx_eq!(f64, obb.half_extents.y, 1.0, epsilon = 0.0001) );
+        assert!( approx_eq!(f64, obb.half_extents.z, 0.5, epsilon = 0.0001) );
+        assert!( approx_eq!(f64, obb.rotation[0].dot_product(axis_x).abs(), 1.0, epsilon = 0.0001) );
+        assert!( approx_eq!(f64, obb.center.length(), 0.0, epsilon = 0.0001) );
+
+        for point in &points {
+            assert!(obb.contains_point(point.scalar_multiplication(0.999)));
+        }
+    }
+
+    #[test]
+    fn test_from_no_points() {
+        // Act & Assert
+        assert!(Obb3::<f64>::from_points(&[]).is_none());
+    }
+
+    #[test]
+    fn test_contains_point() {
+        // Arrange
+        let obb = axis_aligned_box(Vector3::new(1.0, 1.0, 1.0), Vector3::new(1.0, 2.0, 3.0));
+
+        // Act & Assert
+        assert!(obb.contains_point(Vector3::new(1.5, 2.5, -1.5)));
+        assert!(!obb.contains_point(Vector3::new(2.5, 1.0, 1.0)));
+    }
+
+    #[test]
+    fn test_intersects() {
+        // Arrange
+        let obb_a = axis_aligned_box(Vector3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 1.0, 1.0));
+        let obb_b = axis_aligned_box(Vector3::new(1.5, 0.0, 0.0), Vector3::new(1.0, 1.0, 1.0));
+        let obb_c = axis_aligned_box(Vector3::new(2.5, 0.0, 0.0), Vector3::new(1.0, 1.0, 1.0));
+
+        // Act & Assert
+        assert!(obb_a.intersects(obb_b));
+        assert!(!obb_a.intersects(obb_c));
+    }
+
+    #[test]
+    fn test_intersects_rotated() {
+        // Arrange
+        // Rotated 45 degrees, the corner of the other box reaches sqrt(2) from its center towards the first box
+        let diagonal = 0.5_f64.sqrt();
+        let rotation = [
+            Vector3::new(diagonal, diagonal, 0.0),
+            Vector3::new(-diagonal, diagonal, 0.0),
+            Vector3::new(0.0, 0.0, 1.0)
+        ];
+        let obb_a = axis_aligned_box(Vector3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 1.0, 1.0));
+        let obb_close = Obb3::new(Vector3::new(2.3, 0.0, 0.0), Vector3::new(1.0, 1.0, 1.0), rotation);
+        let obb_far = Obb3::new(Vector3::new(2.5, 0.0, 0.0), Vector3::new(1.0, 1.0, 1.0), rotation);
+
+        // Act & Assert
+        assert!(obb_a.intersects(obb_close));
+        assert!(!obb_a.intersects(obb_far));
+    }
+
+    #[test]
+    fn test_display() {
+        // Arrange
+        let obb = axis_aligned_box(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.5, 0.5, 0.5));
+
+        // Act
+        let display = format!("{:.1}", obb);
+
+        // Assert
+        assert_eq!(display, "center (1.0, 2.0, 3.0), half extents (0.5, 0.5, 0.5), rotation [(1.0, 0.0, 0.0), (0.0, 1.0, 0.0), (0.0, 0.0, 1.0)]");
+    }
+
+    #[test]
+    fn test_transform() {
+        // Arrange
+        let obb = Obb3::new(Vector3::<f32>::new(1.0, 0.0, 0.0), Vector3::new(1.0, 1.0, 1.0), [
+            Vector3::new(1.0, 0.0, 0.0),
+            Vector3::new(0.0, 1.0, 0.0),
+            Vector3::new(0.0, 0.0, 1.0)
+        ]);
+        let matrix = Matrix4x4::identity().scale(2.0, 1.0, 1.0);
+
+        // Act
+        let transformed_obb = obb.transform(&matrix);
+
+        // Assert
+        assert_eq!(transformed_obb.center.x, 2.0);
+        assert_eq!(transformed_obb.half_extents.x, 2.0);
+        assert_eq!(transformed_obb.half_extents.y, 1.0);
+        assert_eq!(transformed_obb.rotation[0].x, 1.0);
+    }
+
+    #[test]
+    fn test_transform_rotated_box_with_non_uniform_scale() {
+        // Arrange
+        // Stretching a box that is rotated 45 degrees along x skews it into a parallelepiped
+        let diagonal = core::f32::consts::FRAC_1_SQRT_2;
+        let obb = Obb3::new(Vector3::<f32>::new(0.0, 0.0, 0.0), Vector3::new(2.0, 1.0, 0.5), [
+            Vector3::new(diagonal, diagonal, 0.0),
+            Vector3::new(-diagonal, diagonal, 0.0),
+            Vector3::new(0.0, 0.0, 1.0)
+        ]);
+        let matrix = Matrix4x4::identity().translate(Vector2::new(3.0, 1.0)).scale(3.0, 1.0, 1.0);
+
+        // Act
+        let transformed_obb = obb.transform(&matrix);
+
+        // Assert
+        let axes = transformed_obb.rotation;
+        for i in 0..3 {
+            assert!( approx_eq!(f32, axes[i].length(), 1.0, epsilon = 0.0001) );
+            assert!( approx_eq!(f32, axes[i].dot_product(axes[(i + 1) % 3]), 0.0, epsilon = 0.0001) );
+        }
+
+        // Every corner of the skewed box, pulled in slightly to stay clear of rounding errors, lies inside the result
+        for corner in 0..8 {
+            let sign = |bit: usize| if corner & bit == 0 { -0.999 } else { 0.999 };
+            let point = obb.rotation[0].scalar_multiplication(sign(1) * obb.half_extents.x)
+                + obb.rotation[1].scalar_multiplication(sign(2) * obb.half_extents.y)
+                + obb.rotation[2].scalar_multiplication(sign(4) * obb.half_extents.z);
+
+            assert!(transformed_obb.contains_point(matrix.transform_point(point)));
+        }
+    }
+}
@@ -0,0 +1,180 @@
+use core::error::Error;
+use core::fmt;
+use core::ops::Range;
+use core::str::FromStr;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum ParseErrorKind {
+    // The token could not be parsed as a number. "component" is the index of the token, counting from 0.
+    InvalidNumber { component: usize },
+    MissingComponents { expected: usize, found: usize },
+    TooManyComponents { expected: usize },
+    UnbalancedBracket
+}
+
+// The error returned when parsing a vector or matrix fails.
+// The span is the range of bytes in the input string that caused the error, so it can be pointed out to the user.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ParseError {
+    kind: ParseErrorKind,
+    span: Range<usize>
+}
+
+impl ParseError {
+    pub fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            ParseErrorKind::InvalidNumber { component } => write!(f, "invalid number for component {}", component)?,
+            ParseErrorKind::MissingComponents { expected, found } => write!(f, "expected {} components but found only {}", expected, found)?,
+            ParseErrorKind::TooManyComponents { expected } => write!(f, "expected only {} components", expected)?,
+            ParseErrorKind::UnbalancedBracket => write!(f, "unbalanced bracket")?
+        }
+
+        write!(f, " at bytes {}..{}", self.span.start, self.span.end)
+    }
+}
+
+impl Error for ParseError {}
+
+fn is_separator(character: char) -> bool {
+    character == ',' || character.is_whitespace() || is_bracket(character)
+}
+
+fn is_bracket(character: char) -> bool {
+    matches!(character, '(' | ')' | '[' | ']')
+}
+
+// Reads exactly "expected" numbers from the string. The numbers may be separated by commas and whitespace, and grouped by brackets or parentheses,
+// which is enough to read both "1, 2, 3" and "(1, 2, 3)", as well as the rows of a matrix written as "[1, 2], [3, 4]" or one row per line.
+// "store" is called with the index and value of every component, in order.
+pub(crate) fn parse_each_component<T: FromStr, F: FnMut(usize, T)>(s: &str, expected: usize, mut store: F) -> Result<(), ParseError> {
+    check_brackets(s)?;
+
+    let mut found = 0;
+    let mut token_start: Option<usize> = None;
+
+    // A trailing separator makes sure that the last token is ended as well
+    for (position, character) in s.char_indices().chain(core::iter::once((s.len(), ' '))) {
+        match (token_start, is_separator(character)) {
+            (None, false) => token_start = Some(position),
+            (Some(start), true) => {
+                let span = start..position;
+
+                if found == expected {
+                    return Err(ParseError { kind: ParseErrorKind::TooManyComponents { expected }, span });
+                }
+
+                match s[span.clone()].parse::<T>() {
+                    Ok(value) => store(found, value),
+                    Err(_) => return Err(ParseError { kind: ParseErrorKind::InvalidNumber { component: found }, span })
+                }
+
+                found += 1;
+                token_start = None;
+            },
+            _ => {}
+        }
+    }
+
+    if found < expected {
+        return Err(ParseError { kind: ParseErrorKind::MissingComponents { expected, found }, span: s.len()..s.len() });
+    }
+
+    Ok(())
+}
+
+fn check_brackets(s: &str) -> Result<(), ParseError> {
+    let mut depth: usize = 0;
+    let mut last_opening = 0;
+
+    for (position, character) in s.char_indices() {
+        match character {
+            '(' | '[' => {
+                depth += 1;
+                last_opening = position;
+            },
+            ')' | ']' => {
+                if depth == 0 {
+                    return Err(ParseError { kind: ParseErrorKind::UnbalancedBracket, span: position..position + 1 });
+                }
+
+                depth -= 1;
+            },
+            _ => {}
+        }
+    }
+
+    if depth > 0 {
+        return Err(ParseError { kind: ParseErrorKind::UnbalancedBracket, span: last_opening..last_opening + 1 });
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_components<T: FromStr + Copy, const N: usize>(s: &str) -> Result<[T; N], ParseError> {
+        let mut values: [Option<T>; N] = [None; N];
+
+        parse_each_component(s, N, |index, value| values[index] = Some(value))?;
+
+        Ok(values.map(|value| value.unwrap()))
+    }
+
+    #[test]
+    fn test_parse_components() {
+        // Act
+        let plain = parse_components::<f32, 3>("1.0, 2.5, -3");
+        let parenthesized = parse_components::<f32, 3>("(1.0, 2.5, -3)");
+
+        // Assert
+        assert_eq!(plain, Ok([1.0, 2.5, -3.0]));
+        assert_eq!(parenthesized, Ok([1.0, 2.5, -3.0]));
+    }
+
+    #[test]
+    fn test_invalid_number() {
+        // Act
+        let error = parse_components::<f32, 3>("1.0, 2.x, 3.0").unwrap_err();
+
+        // Assert
+        assert_eq!(error.kind(), ParseErrorKind::InvalidNumber { component: 1 });
+        assert_eq!(error.span(), 5..8);
+        assert_eq!(error.to_string(), "invalid number for component 1 at bytes 5..8");
+    }
+
+    #[test]
+    fn test_wrong_number_of_components() {
+        // Act
+        let too_few = parse_components::<f32, 3>("1.0, 2.0").unwrap_err();
+        let too_many = parse_components::<f32, 2>("1.0, 2.0, 3.0").unwrap_err();
+
+        // Assert
+        assert_eq!(too_few.kind(), ParseErrorKind::MissingComponents { expected: 3, found: 2 });
+        assert_eq!(too_many.kind(), ParseErrorKind::TooManyComponents { expected: 2 });
+        assert_eq!(too_many.span(), 10..13);
+    }
+
+    #[test]
+    fn test_unbalanced_brackets() {
+        // Act
+        let unclosed = parse_components::<f32, 2>("(1.0, 2.0").unwrap_err();
+        let unopened = parse_components::<f32, 2>("1.0, 2.0]").unwrap_err();
+
+        // Assert
+        assert_eq!(unclosed.kind(), ParseErrorKind::UnbalancedBracket);
+        assert_eq!(unclosed.span(), 0..1);
+        assert_eq!(unopened.span(), 8..9);
+    }
+}
@@ -0,0 +1,359 @@
+use core::fmt;
+
+use crate::vector3::Vector3;
+use crate::matrix4x4::Matrix4x4;
+use crate::float_trait::Float;
+use crate::ray::Ray;
+use crate::line::Line;
+
+// A plane is stored in the form "normal . p + distance = 0".
+// For any point p, "normal . p + distance" is then the signed distance from the plane to the point,
+// provided that the normal has unit length. Points on the side the normal points towards have a positive distance.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Plane<T> where T: Float {
+    pub normal: Vector3<T>,
+    pub distance: T
+}
+
+impl<T: Float> Plane<T> {
+    pub fn new(normal: Vector3<T>, distance: T) -> Plane<T> {
+        Plane {
+            normal,
+            distance
+        }
+    }
+
+    pub fn from_point_normal(point: Vector3<T>, normal: Vector3<T>) -> Plane<T> {
+        Plane {
+            normal,
+            distance: -normal.dot_product(point)
+        }
+    }
+
+    // Constructs the plane passing through three points. The normal faces the side from which the points appear in counter-clockwise order.
+    // Returns None if the points are collinear, since they then don't define a single plane.
+    pub fn from_points(a: Vector3<T>, b: Vector3<T>, c: Vector3<T>) -> Option<Plane<T>> {
+        let normal = (b - a).cross_product(c - a);
+        let length = normal.length();
+
+        if length <= T::epsilon() {
+            return None;
+        }
+
+        Some(Plane::from_point_normal(a, normal.scalar_multiplication(T::one() / length)))
+    }
+
+    // Scales the plane equation so that the normal has unit length.
+    // Planes extracted from a matrix are generally NOT normalized, and distances measured against them are scaled.
+    pub fn normalize(self) -> Plane<T> {
+        let length = self.normal.length();
+
+        if length == T::zero() {
+            return self;
+        }
+
+        let inverse_length = T::one() / length;
+
+        Plane {
+            normal: self.normal.scalar_multiplication(inverse_length),
+            distance: self.distance * inverse_length
+        }
+    }
+
+    pub fn signed_distance(self, point: Vector3<T>) -> T {
+        self.normal.dot_product(point) + self.distance
+    }
+
+    // Finds the point on the plane closest to the given point.
+    pub fn project_point(self, point: Vector3<T>) -> Vector3<T> {
+        let scale = self.signed_distance(point) / self.normal.dot_product(self.normal);
+
+        point - self.normal.scalar_multiplication(scale)
+    }
+
+    // Returns None if the line is parallel to the plane.
+    pub fn intersect_line(self, line: Line<T>) -> Option<Vector3<T>> {
+        let denominator = self.normal.dot_product(line.direction);
+
+        if denominator.abs() <= T::epsilon() {
+            return None;
+        }
+
+        Some(line.point_at(-self.signed_distance(line.point) / denominator))
+    }
+
+    // Returns the distance along the ray at which it hits the plane,
+    // or None if the ray is parallel to the plane or points away from it.
+    pub fn intersect_ray(self, ray: Ray<T>) -> Option<T> {
+        let denominator = self.normal.dot_product(ray.direction);
+
+        if denominator.abs() <= T::epsilon() {
+            return None;
+        }
+
+        let distance = -self.signed_distance(ray.origin) / denominator;
+
+        if distance < T::zero() {
+            return None;
+        }
+
+        Some(distance)
+    }
+
+    // Returns the line along which the two planes meet, or None if the planes are parallel.
+    pub fn intersect_plane(self, other: Plane<T>) -> Option<Line<T>> {
+        let direction = self.normal.cross_product(other.normal);
+        let direction_length_squared = direction.dot_product(direction);
+
+        if direction_length_squared <= T::epsilon() {
+            return None;
+        }
+
+        let point = (other.normal.cross_product(direction).scalar_multiplication(-self.distance)
+            + direction.cross_product(self.normal).scalar_multiplication(-other.distance))
+            .scalar_multiplication(T::one() / direction_length_squared);
+
+        Some(Line::new(point, direction))
+    }
+
+    // Finds the single point shared by three planes.
+    // Returns None if two or more of the planes are parallel, in which case there is no single intersection point.
+    pub fn intersect_three(a: Plane<T>, b: Plane<T>, c: Plane<T>) -> Option<Vector3<T>> {
+        let b_cross_c = b.normal.cross_product(c.normal);
+        let denominator = a.normal.dot_product(b_cross_c);
+
+        if denominator.abs() <= T::epsilon() {
+            return None;
+        }
+
+        let c_cross_a = c.normal.cross_product(a.normal);
+        let a_cross_b = a.normal.cross_product(b.normal);
+
+        let numerator = b_cross_c.scalar_multiplication(-a.distance)
+            + c_cross_a.scalar_multiplication(-b.distance)
+            + a_cross_b.scalar_multiplication(-c.distance);
+
+        Some(numerator.scalar_multiplication(T::one() / denominator))
+    }
+}
+
+impl Plane<f32> {
+    // Planes do not transform like points. Writing the plane as the row vector [n, d], a point p lies on the plane when [n, d] * p = 0.
+    // For the transformed point M * p to lie on the transformed plane, the plane must be multiplied by the inverse of M.
+    // Returns None if the matrix cannot be inverted.
+    pub fn transform(self, matrix: &Matrix4x4) -> Option<Plane<f32>> {
+        let inverse = matrix.inverse()?;
+
+        let transformed = |column: usize| {
+            self.normal.x * inverse[[0, column]] + self.normal.y * inverse[[1, column]] + self.normal.z * inverse[[2, column]] + self.distance * inverse[[3, column]]
+        };
+
+        let plane = Plane::new(Vector3::new(transformed(0), transformed(1), transformed(2)), transformed(3));
+
+        Some(plane.normalize())
+    }
+}
+
+impl<T: Float + fmt::Display> fmt::Display for Plane<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "normal ")?;
+        fmt::Display::fmt(&self.normal, f)?;
+        write!(f, ", distance ")?;
+        fmt::Display::fmt(&self.distance, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use float_cmp::approx_eq;
+
+    #[test]
+    fn test_normalize() {
+        // Arrange
+        let plane = Plane::new(Vector3::<f64>::new(0.0, 2.0, 0.0), 4.0);
+
+        // Act
+        let normalized_plane = plane.normalize();
+
+        // Assert
+        assert!( approx_eq!(f64, normalized_plane.normal.length(), 1.0, epsilon = 0.0001) );
+        assert!( approx_eq!(f64, normalized_plane.distance, 2.0, epsilon = 0.0001) );
+    }
+
+    #[test]
+    fn test_signed_distance() {
+        // Arrange
+        let plane = Plane::new(Vector3::<f64>::new(0.0, 1.0, 0.0), -2.0);
+
+        // Act
+        let distance_above = plane.signed_distance(Vector3::new(5.0, 5.0, 0.0));
+        let distance_below = plane.signed_distance(Vector3::new(5.0, 0.0, 0.0));
+
+        // Assert
+        assert_eq!(distance_above, 3.0);
+        assert_eq!(distance_below, -2.0);
+    }
+
+    #[test]
+    fn test_intersect_three() {
+        // Arrange
+        let plane_a = Plane::new(Vector3::<f64>::new(1.0, 0.0, 0.0), -1.0);
+        let plane_b = Plane::new(Vector3::<f64>::new(0.0, 1.0, 0.0), -2.0);
+        let plane_c = Plane::new(Vector3::<f64>::new(0.0, 0.0, 1.0), -3.0);
+
+        // Act
+        let intersection = Plane::intersect_three(plane_a, plane_b, plane_c).unwrap();
+
+        // Assert
+        assert!( approx_eq!(f64, intersection.x, 1.0, epsilon = 0.0001) );
+        assert!( approx_eq!(f64, intersection.y, 2.0, epsilon = 0.0001) );
+        assert!( approx_eq!(f64, intersection.z, 3.0, epsilon = 0.0001) );
+    }
+
+    #[test]
+    fn test_intersect_three_parallel() {
+        // Arrange
+        let plane_a = Plane::new(Vector3::<f64>::new(1.0, 0.0, 0.0), -1.0);
+        let plane_b = Plane::new(Vector3::<f64>::new(1.0, 0.0, 0.0), -2.0);
+        let plane_c = Plane::new(Vector3::<f64>::new(0.0, 0.0, 1.0), -3.0);
+
+        // Act
+        let intersection = Plane::intersect_three(plane_a, plane_b, plane_c);
+
+        // Assert
+        assert!(intersection.is_none());
+    }
+
+    #[test]
+    fn test_display() {
+        // Arrange
+        let plane = Plane::new(Vector3::<f64>::new(0.0, 1.0, 0.0), -2.5);
+
+        // Act
+        let display = format!("{}", plane);
+        let debug = format!("{:?}", plane);
+
+        // Assert
+        assert_eq!(display, "normal (0, 1, 0), distance -2.5");
+        assert_eq!(debug, "Plane { normal: Vector3 { x: 0.0, y: 1.0, z: 0.0 }, distance: -2.5 }");
+    }
+
+    #[test]
+    fn test_from_points() {
+        // Arrange
+        let point_a = Vector3::<f64>::new(0.0, 1.0, 0.0);
+        let point_b = Vector3::<f64>::new(1.0, 1.0, 0.0);
+        let point_c = Vector3::<f64>::new(0.0, 1.0, -1.0);
+
+        // Act
+        let plane = Plane::from_points(point_a, point_b, point_c).unwrap();
+
+        // Assert
+        assert!( approx_eq!(f64, plane.normal.y, 1.0, epsilon = 0.0001) );
+        assert!( approx_eq!(f64, plane.distance, -1.0, epsilon = 0.0001) );
+    }
+
+    #[test]
+    fn test_from_collinear_points() {
+        // Act
+        let plane = Plane::from_points(Vector3::<f64>::new(0.0, 0.0, 0.0), Vector3::new(1.0, 1.0, 1.0), Vector3::new(2.0, 2.0, 2.0));
+
+        // Assert
+        assert!(plane.is_none());
+    }
+
+    #[test]
+    fn test_project_point() {
+        // Arrange
+        let plane = Plane::from_point_normal(Vector3::<f64>::new(0.0, 0.0, 2.0), Vector3::new(0.0, 0.0, 1.0));
+
+        // Act
+        let projected_point = plane.project_point(Vector3::new(3.0, 4.0, 7.0));
+
+        // Assert
+        assert_eq!(projected_point.x, 3.0);
+        assert_eq!(projected_point.y, 4.0);
+        assert_eq!(projected_point.z, 2.0);
+    }
+
+    #[test]
+    fn test_intersect_line() {
+        // Arrange
+        let plane = Plane::new(Vector3::<f64>::new(0.0, 1.0, 0.0), -2.0);
+        let line = Line::new(Vector3::new(1.0, 5.0, 1.0), Vector3::new(0.0, 1.0, 0.0));
+
+        // Act
+        let intersection = plane.intersect_line(line).unwrap();
+
+        // Assert
+        assert_eq!(intersection.x, 1.0);
+        assert_eq!(intersection.y, 2.0);
+        assert_eq!(intersection.z, 1.0);
+    }
+
+    #[test]
+    fn test_intersect_ray() {
+        // Arrange
+        let plane = Plane::new(Vector3::<f64>::new(0.0, 1.0, 0.0), -2.0);
+        let ray_towards = Ray::new(Vector3::new(0.0, 5.0, 0.0), Vector3::new(0.0, -1.0, 0.0));
+        let ray_away = Ray::new(Vector3::new(0.0, 5.0, 0.0), Vector3::new(0.0, 1.0, 0.0));
+
+        // Act
+        let distance_towards = plane.intersect_ray(ray_towards);
+        let distance_away = plane.intersect_ray(ray_away);
+
+        // Assert
+        assert_eq!(distance_towards, Some(3.0));
+        assert!(distance_away.is_none());
+    }
+
+    #[test]
+    fn test_intersect_plane() {
+        // Arrange
+        let plane_a = Plane::new(Vector3::<f64>::new(1.0, 0.0, 0.0), -1.0);
+        let plane_b = Plane::new(Vector3::<f64>::new(0.0, 1.0, 0.0), -2.0);
+
+        // Act
+        let line = plane_a.intersect_plane(plane_b).unwrap();
+
+        // Assert
+        assert!( approx_eq!(f64, line.point.x, 1.0, epsilon = 0.0001) );
+        assert!( approx_eq!(f64, line.point.y, 2.0, epsilon = 0.0001) );
+        assert!( approx_eq!(f64, line.direction.x, 0.0, epsilon = 0.0001) );
+        assert!( approx_eq!(f64, line.direction.y, 0.0, epsilon = 0.0001) );
+        assert!( approx_eq!(f64, line.direction.z.abs(), 1.0, epsilon = 0.0001) );
+    }
+
+    #[test]
+    fn test_transform() {
+        // Arrange
+        let plane = Plane::new(Vector3::<f32>::new(0.0, 1.0, 0.0), -1.0);
+        let matrix = Matrix4x4::identity().scale(1.0, 3.0, 1.0);
+
+        // Act
+        let transformed_plane = plane.transform(&matrix).unwrap();
+
+        // Assert
+        assert!( approx_eq!(f32, transformed_plane.normal.y, 1.0, epsilon = 0.0001) );
+        assert!( approx_eq!(f32, transformed_plane.distance, -3.0, epsilon = 0.0001) );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        // Arrange
+        let plane = Plane::new(Vector3::<f64>::new(0.0, 1.0, 0.0), -2.5);
+
+        // Act
+        let json = serde_json::to_string(&plane).unwrap();
+        let deserialized_plane: Plane<f64> = serde_json::from_str(&json).unwrap();
+
+        // Assert
+        assert_eq!(json, r#"{"normal":[0.0,1.0,0.0],"distance":-2.5}"#);
+        assert_eq!(deserialized_plane.normal, plane.normal);
+        assert_eq!(deserialized_plane.distance, plane.distance);
+    }
+}
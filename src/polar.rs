@@ -0,0 +1,108 @@
+use crate::float_trait::Float;
+use crate::matrix::Matrix;
+use crate::interpolation::Lerp;
+
+// The polar decomposition of a 3x3 matrix A = R S, into a rotation R followed by a symmetric stretch S.
+// Any linear transformation can be written this way, including ones with shear, which a rotation and a scale along the axes cannot express.
+// R is always a proper rotation. If A contains a reflection, it ends up in S, which then has a negative eigenvalue.
+#[derive(Copy, Clone, Debug)]
+pub struct PolarDecomposition<T> where T: Float {
+    pub rotation: Matrix<T, 3, 3>,
+    pub stretch: Matrix<T, 3, 3>
+}
+
+impl<T: Float> Matrix<T, 3, 3> {
+    // With the SVD A = U diag(s) V^T, the rotation is U V^T and the stretch is V diag(s) V^T.
+    pub fn polar_decomposition(&self) -> PolarDecomposition<T> {
+        let svd = self.svd();
+        let [s0, s1, s2] = svd.singular_values;
+        let scale = Matrix::from_rows([[s0, T::zero(), T::zero()], [T::zero(), s1, T::zero()], [T::zero(), T::zero(), s2]]);
+
+        PolarDecomposition {
+            rotation: svd.u.mul(svd.v.transpose()),
+            stretch: svd.v.mul(scale).mul(svd.v.transpose())
+        }
+    }
+}
+
+impl<T: Float> Matrix<T, 4, 4> {
+    // Interpolates between two affine transformations, which may include shear and non-uniform scale, not only translation, rotation and scale.
+    // Both are split into translation, rotation and stretch. The rotations are interpolated along the shortest arc,
+    // while the stretches and translations are interpolated linearly, and the parts are put back together.
+    // Interpolating the elements directly would shrink the object halfway through a rotation, which this avoids.
+    // The bottom row of both matrices is expected to be (0, 0, 0, 1), and the result has the same bottom row.
+    pub fn interpolate_affine(&self, other: Matrix<T, 4, 4>, t: T) -> Matrix<T, 4, 4> {
+        let linear_part = |matrix: &Matrix<T, 4, 4>| Matrix::<T, 3, 3>::from_fn(|row, column| matrix[[row, column]]);
+
+        let polar_a = linear_part(self).polar_decomposition();
+        let polar_b = linear_part(&other).polar_decomposition();
+
+        let rotation = polar_a.rotation.rotation_interpolate(polar_b.rotation, t);
+        let stretch = polar_a.stretch.lerp(polar_b.stretch, t);
+        let linear = rotation.mul(stretch);
+
+        Matrix::from_fn(|row, column| {
+            match (row, column) {
+                (3, 3) => T::one(),
+                (3, _) => T::zero(),
+                (_, 3) => self[[row, 3]] + (other[[row, 3]] - self[[row, 3]]) * t,
+                _ => linear[[row, column]]
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vector2::Vector2;
+    use crate::vector3::Vector3;
+    use crate::matrix4x4::Matrix4x4;
+    use float_cmp::approx_eq;
+
+    #[test]
+    fn test_polar_decomposition() {
+        // Arrange
+        // A rotation applied after a shear
+        let shear = Matrix::<f64, 3, 3>::from_rows([[1.0, 0.5, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 2.0]]);
+        let matrix = Matrix::rotation_exp(Vector3::new(0.2, 0.4, -0.3)).mul(shear);
+
+        // Act
+        let polar = matrix.polar_decomposition();
+        let product = polar.rotation.mul(polar.stretch);
+
+        // Assert
+        assert!( approx_eq!(f64, polar.rotation.determinant(), 1.0, epsilon = 0.0001) );
+
+        for row in 0..3 {
+            for column in 0..3 {
+                assert!( approx_eq!(f64, product[[row, column]], matrix[[row, column]], epsilon = 0.0001) );
+                assert!( approx_eq!(f64, polar.stretch[[row, column]], polar.stretch[[column, row]], epsilon = 0.0001) );
+            }
+        }
+    }
+
+    #[test]
+    fn test_interpolate_affine() {
+        // Arrange
+        let a = Matrix4x4::identity();
+        let b = Matrix4x4::identity().translate(Vector2::new(4.0, 2.0)).rotate(0.0, 0.0, core::f32::consts::FRAC_PI_2).scale(3.0, 3.0, 3.0);
+
+        // Act
+        let halfway = a.interpolate_affine(b, 0.5);
+        let end = a.interpolate_affine(b, 1.0);
+
+        // Assert
+        // Halfway, the rotation is 45 degrees and the scale is 2, so the x axis is rotated and scaled without shrinking in between
+        let x_axis = halfway.transform_vector(Vector3::new(1.0, 0.0, 0.0));
+        assert!( approx_eq!(f32, x_axis.length(), 2.0, epsilon = 0.0001) );
+        assert!( approx_eq!(f32, x_axis.x, x_axis.y, epsilon = 0.0001) );
+        assert!( approx_eq!(f32, halfway[[0, 3]], 2.0, epsilon = 0.0001) );
+
+        for row in 0..4 {
+            for column in 0..4 {
+                assert!( approx_eq!(f32, end[[row, column]], b[[row, column]], epsilon = 0.0001) );
+            }
+        }
+    }
+}
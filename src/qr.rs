@@ -0,0 +1,265 @@
+use alloc::vec::Vec;
+
+use crate::float_trait::Float;
+use crate::dmatrix::{DMatrix, DVector};
+
+// QR decomposition using Householder reflections: an m x n matrix A with m >= n is written as A = QR,
+// where Q has orthonormal columns and R is n x n and upper triangular.
+// Since Q preserves lengths, minimizing |Ax - b| is the same as minimizing |Rx - Q^T b|, which is solved by back substitution.
+// This is why QR is used for least-squares problems instead of solving the normal equations A^T A x = A^T b,
+// which squares the condition number of the matrix and loses about half of the available precision.
+//
+// Every step reflects the part of the current column below the diagonal onto the diagonal, zeroing the elements below it.
+// Q is never formed unless asked for. The reflections are stored as their unit normal vectors and applied one by one instead.
+#[derive(Clone, Debug)]
+pub struct Qr<T> where T: Float {
+    // Column k holds the normal of the k'th reflection. Only the rows from k and down are used.
+    reflections: DMatrix<T>,
+    r: DMatrix<T>,
+    rank_deficient: bool
+}
+
+impl<T: Float> Qr<T> {
+    // Panics if the matrix has fewer rows than columns, since the system is then underdetermined and has no unique least-squares solution
+    pub fn new(matrix: &DMatrix<T>) -> Qr<T> {
+        let (rows, columns) = matrix.shape();
+
+        if rows < columns {
+            panic!("QR decomposition needs at least as many rows as columns, but the matrix is {}x{}!", rows, columns);
+        }
+
+        let mut a = matrix.clone();
+        let mut reflections = DMatrix::zeros(rows, columns);
+        let mut scale = T::zero();
+
+        for element in matrix.as_slice() {
+            if element.abs() > scale {
+                scale = element.abs();
+            }
+        }
+
+        let tolerance = scale * T::from_f64(rows as f64) * T::epsilon();
+        let mut rank_deficient = false;
+
+        for k in 0..columns {
+            let mut column_length = T::zero();
+
+            for row in k..rows {
+                column_length = column_length + a[[row, k]] * a[[row, k]];
+            }
+
+            column_length = column_length.sqrt();
+
+            // Reflecting onto the diagonal in the direction away from the current diagonal element avoids subtracting two nearly equal numbers
+            let alpha = if a[[k, k]] > T::zero() { -column_length } else { column_length };
+
+            let mut normal: Vec<T> = (k..rows).map(|row| a[[row, k]]).collect();
+            normal[0] = normal[0] - alpha;
+
+            let normal_length = normal.iter().fold(T::zero(), |sum, element| sum + *element * *element).sqrt();
+
+            // A column that is already zero below the diagonal needs no reflection
+            if normal_length > T::zero() {
+                for (offset, element) in normal.iter().enumerate() {
+                    reflections[[k + offset, k]] = *element / normal_length;
+                }
+
+                for column in k..columns {
+                    reflect(reflections.column(k), k, a.column_mut(column));
+                }
+            }
+
+            let is_pivot_usable = a[[k, k]].abs() > tolerance;
+
+            if !is_pivot_usable {
+                rank_deficient = true;
+            }
+        }
+
+        let r = DMatrix::from_fn(columns, columns, |row, column| if row <= column { a[[row, column]] } else { T::zero() });
+
+        Qr {
+            reflections,
+            r,
+            rank_deficient
+        }
+    }
+
+    // The n x n upper triangular factor
+    pub fn r(&self) -> DMatrix<T> {
+        self.r.clone()
+    }
+
+    // The m x n factor with orthonormal columns, found by applying the reflections to the first n columns of the identity matrix
+    pub fn q(&self) -> DMatrix<T> {
+        let (rows, columns) = self.reflections.shape();
+        let mut q = DMatrix::from_fn(rows, columns, |row, column| if row == column { T::one() } else { T::zero() });
+
+        for column in 0..columns {
+            for k in (0..columns).rev() {
+                reflect(self.reflections.column(k), k, q.column_mut(column));
+            }
+        }
+
+        q
+    }
+
+    // True if the columns of the matrix are linearly dependent, in which case the least-squares solution is not unique
+    pub fn is_rank_deficient(&self) -> bool {
+        self.rank_deficient
+    }
+
+    // Computes Q^T b, which has the length of the columns of the matrix
+    pub fn q_transpose_mul(&self, b: &DVector<T>) -> DVector<T> {
+        let (rows, columns) = self.reflections.shape();
+
+        if b.len() != rows {
+            panic!("Cannot multiply the transpose of a {}x{} matrix with a vector of length {}!", rows, columns, b.len());
+        }
+
+        let mut result = b.clone();
+
+        for k in 0..columns {
+            reflect(self.reflections.column(k), k, result.as_mut_slice());
+        }
+
+        result
+    }
+
+    // Finds the x that minimizes |Ax - b|. Returns None if the matrix is rank deficient.
+    pub fn solve_least_squares(&self, b: &DVector<T>) -> Option<DVector<T>> {
+        if self.rank_deficient {
+            return None;
+        }
+
+        let columns = self.r.columns();
+        let projected = self.q_transpose_mul(b);
+        let mut x = DVector::zeros(columns);
+
+        for row in (0..columns).rev() {
+            let mut sum = projected[row];
+
+            for k in row + 1..columns {
+                sum = sum - self.r[[row, k]] * x[k];
+            }
+
+            x[row] = sum / self.r[[row, row]];
+        }
+
+        Some(x)
+    }
+}
+
+// Applies the reflection I - 2nn^T with the unit normal n to the target vector.
+// Only the rows from k and down are touched, since the normal of the k'th reflection is zero above that.
+fn reflect<T: Float>(normal: &[T], k: usize, target: &mut [T]) {
+    let two = T::one() + T::one();
+    let projection = normal[k..].iter().zip(&target[k..]).fold(T::zero(), |sum, (n, t)| sum + *n * *t);
+
+    for (n, t) in normal[k..].iter().zip(&mut target[k..]) {
+        *t = *t - two * projection * *n;
+    }
+}
+
+impl<T: Float> DMatrix<T> {
+    pub fn qr(&self) -> Qr<T> {
+        Qr::new(self)
+    }
+}
+
+// Finds the x that minimizes |Ax - b|, the least-squares solution of an overdetermined system.
+// Returns None if the columns of A are linearly dependent. Panics if A has fewer rows than columns, or if b does not have one element per row.
+pub fn solve_least_squares<T: Float>(a: &DMatrix<T>, b: &DVector<T>) -> Option<DVector<T>> {
+    a.qr().solve_least_squares(b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use float_cmp::approx_eq;
+
+    fn assert_matrices_equal(a: &DMatrix<f64>, b: &DMatrix<f64>) {
+        assert_eq!(a.shape(), b.shape());
+
+        for (element_a, element_b) in a.as_slice().iter().zip(b.as_slice()) {
+            assert!( approx_eq!(f64, *element_a, *element_b, epsilon = 0.0001) );
+        }
+    }
+
+    #[test]
+    fn test_decomposition_reproduces_matrix() {
+        // Arrange
+        let matrix = DMatrix::from_rows_slice(4, 3, &[
+            12.0, -51.0, 4.0,
+            6.0, 167.0, -68.0,
+            -4.0, 24.0, -41.0,
+            1.0, 2.0, 3.0]);
+
+        // Act
+        let qr = matrix.qr();
+        let q = qr.q();
+        let r = qr.r();
+
+        // Assert
+        assert_matrices_equal(&(&q * &r), &matrix);
+        assert_matrices_equal(&(&q.transpose() * &q), &DMatrix::identity(3));
+
+        for row in 1..3 {
+            for column in 0..row {
+                assert_eq!(r[[row, column]], 0.0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_least_squares_line() {
+        // Arrange
+        // Fitting y = c0 + c1 * x to noisy points around y = 1 + 2x
+        let xs = [0.0, 1.0, 2.0, 3.0];
+        let ys = [1.1, 2.9, 5.1, 6.9];
+        let a = DMatrix::from_fn(4, 2, |row, column| if column == 0 { 1.0 } else { xs[row] });
+        let b = DVector::from_slice(&ys);
+
+        // Act
+        let solution = solve_least_squares(&a, &b).unwrap();
+
+        // Assert
+        assert!( approx_eq!(f64, solution[0], 1.06, epsilon = 0.0001) );
+        assert!( approx_eq!(f64, solution[1], 1.96, epsilon = 0.0001) );
+    }
+
+    #[test]
+    fn test_square_system_matches_lu() {
+        // Arrange
+        let a = DMatrix::from_rows_slice(3, 3, &[
+            2.0, 1.0, -1.0,
+            -3.0, -1.0, 2.0,
+            -2.0, 1.0, 2.0]);
+        let b = DVector::from_slice(&[8.0, -11.0, -3.0]);
+
+        // Act
+        let least_squares_solution = solve_least_squares(&a, &b).unwrap();
+        let lu_solution = a.solve(&b).unwrap();
+
+        // Assert
+        for index in 0..3 {
+            assert!( approx_eq!(f64, least_squares_solution[index], lu_solution[index], epsilon = 0.0001) );
+        }
+    }
+
+    #[test]
+    fn test_rank_deficient_matrix() {
+        // Arrange
+        let a = DMatrix::from_rows_slice(3, 2, &[
+            1.0, 2.0,
+            2.0, 4.0,
+            3.0, 6.0]);
+
+        // Act
+        let qr = a.qr();
+
+        // Assert
+        assert!(qr.is_rank_deficient());
+        assert!(qr.solve_least_squares(&DVector::from_slice(&[1.0, 2.0, 3.0])).is_none());
+    }
+}
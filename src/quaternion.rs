@@ -0,0 +1,246 @@
+use core::fmt;
+use core::ops::Mul;
+
+use crate::vector3::Vector3;
+use crate::float_trait::Float;
+use crate::matrix::Matrix;
+
+// A rotation stored as a unit quaternion x i + y j + z k + w. For a rotation by "angle" around the unit "axis",
+// (x, y, z) is the axis scaled by sin(angle / 2), and w is cos(angle / 2).
+// Compared to a rotation matrix it is smaller, cheaper to compose, easy to keep normalized, and interpolates smoothly.
+// "q" and "-q" describe the same rotation.
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Quaternion<T> where T: Float {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+    pub w: T
+}
+
+impl<T: Float> Quaternion<T> {
+    pub fn new(x: T, y: T, z: T, w: T) -> Quaternion<T> {
+        Quaternion {
+            x,
+            y,
+            z,
+            w
+        }
+    }
+
+    pub fn identity() -> Quaternion<T> {
+        Quaternion::new(T::zero(), T::zero(), T::zero(), T::one())
+    }
+
+    // The axis is expected to have unit length, and the angle is in radians
+    pub fn from_axis_angle(axis: Vector3<T>, angle: T) -> Quaternion<T> {
+        let half_angle = angle / (T::one() + T::one());
+        let sin = half_angle.sin();
+
+        Quaternion::new(axis.x * sin, axis.y * sin, axis.z * sin, half_angle.cos())
+    }
+
+    // The matrix is expected to be a rotation. Shepperd's method picks the largest of the four components to divide by,
+    // which avoids the loss of precision that dividing by a tiny w would cause for rotations close to 180 degrees.
+    pub fn from_rotation_matrix(matrix: &Matrix<T, 3, 3>) -> Quaternion<T> {
+        let m = |row: usize, column: usize| matrix[[row, column]];
+        let one = T::one();
+        let quarter = T::from_f64(0.25);
+        let trace = m(0, 0) + m(1, 1) + m(2, 2);
+
+        let quaternion = if trace > T::zero() {
+            let s = (trace + one).sqrt() * (one + one);
+            Quaternion::new((m(2, 1) - m(1, 2)) / s, (m(0, 2) - m(2, 0)) / s, (m(1, 0) - m(0, 1)) / s, quarter * s)
+        } else if m(0, 0) > m(1, 1) && m(0, 0) > m(2, 2) {
+            let s = (one + m(0, 0) - m(1, 1) - m(2, 2)).sqrt() * (one + one);
+            Quaternion::new(quarter * s, (m(0, 1) + m(1, 0)) / s, (m(0, 2) + m(2, 0)) / s, (m(2, 1) - m(1, 2)) / s)
+        } else if m(1, 1) > m(2, 2) {
+            let s = (one + m(1, 1) - m(0, 0) - m(2, 2)).sqrt() * (one + one);
+            Quaternion::new((m(0, 1) + m(1, 0)) / s, quarter * s, (m(1, 2) + m(2, 1)) / s, (m(0, 2) - m(2, 0)) / s)
+        } else {
+            let s = (one + m(2, 2) - m(0, 0) - m(1, 1)).sqrt() * (one + one);
+            Quaternion::new((m(0, 2) + m(2, 0)) / s, (m(1, 2) + m(2, 1)) / s, quarter * s, (m(1, 0) - m(0, 1)) / s)
+        };
+
+        quaternion.normalize()
+    }
+
+    pub fn dot_product(self, other: Quaternion<T>) -> T {
+        self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w
+    }
+
+    pub fn length(self) -> T {
+        self.dot_product(self).sqrt()
+    }
+
+    // Composing many rotations slowly lets the length drift away from 1 through rounding, so normalize every now and then
+    pub fn normalize(self) -> Quaternion<T> {
+        self.scale(T::one() / self.length())
+    }
+
+    // For a unit quaternion the conjugate is the inverse rotation
+    pub fn conjugate(self) -> Quaternion<T> {
+        Quaternion::new(-self.x, -self.y, -self.z, self.w)
+    }
+
+    pub fn rotate_vector(self, vector: Vector3<T>) -> Vector3<T> {
+        // An optimized form of q v q*, see "A faster quaternion-vector multiplication" by Fabian Giesen
+        let axis = Vector3::new(self.x, self.y, self.z);
+        let two = T::one() + T::one();
+        let t = axis.cross_product(vector).scalar_multiplication(two);
+
+        vector + t.scalar_multiplication(self.w) + axis.cross_product(t)
+    }
+
+    pub fn to_matrix(self) -> Matrix<T, 3, 3> {
+        let (x, y, z, w) = (self.x, self.y, self.z, self.w);
+        let one = T::one();
+        let two = one + one;
+
+        Matrix::from_rows([
+            [one - two * (y * y + z * z), two * (x * y - z * w), two * (x * z + y * w)],
+            [two * (x * y + z * w), one - two * (x * x + z * z), two * (y * z - x * w)],
+            [two * (x * z - y * w), two * (y * z + x * w), one - two * (x * x + y * y)]])
+    }
+
+    // Spherical linear interpolation, which rotates at a constant angular speed along the shortest arc between the two rotations.
+    // When the rotations are almost the same, the arc is practically straight, and normalized linear interpolation is used instead to avoid dividing by a tiny sine.
+    pub fn slerp(self, other: Quaternion<T>, t: T) -> Quaternion<T> {
+        let mut other = other;
+        let mut cos_angle = self.dot_product(other);
+
+        // q and -q are the same rotation, but interpolating towards the one on the other side of the sphere would take the long way around
+        if cos_angle < T::zero() {
+            other = other.scale(-T::one());
+            cos_angle = -cos_angle;
+        }
+
+        if cos_angle > T::one() - T::epsilon().sqrt() {
+            return self.scale(T::one() - t).add(other.scale(t)).normalize();
+        }
+
+        let sin_angle = (T::one() - cos_angle * cos_angle).sqrt();
+        let angle = sin_angle.atan2(cos_angle);
+        let a = ((T::one() - t) * angle).sin() / sin_angle;
+        let b = (t * angle).sin() / sin_angle;
+
+        self.scale(a).add(other.scale(b))
+    }
+
+    fn scale(self, scalar: T) -> Quaternion<T> {
+        Quaternion::new(self.x * scalar, self.y * scalar, self.z * scalar, self.w * scalar)
+    }
+
+    fn add(self, other: Quaternion<T>) -> Quaternion<T> {
+        Quaternion::new(self.x + other.x, self.y + other.y, self.z + other.z, self.w + other.w)
+    }
+}
+
+// The Hamilton product. Like matrices, "a * b" rotates by b first and then by a.
+impl<T: Float> Mul for Quaternion<T> {
+    type Output = Quaternion<T>;
+
+    fn mul(self, other: Quaternion<T>) -> Quaternion<T> {
+        Quaternion::new(
+            self.w * other.x + self.x * other.w + self.y * other.z - self.z * other.y,
+            self.w * other.y - self.x * other.z + self.y * other.w + self.z * other.x,
+            self.w * other.z + self.x * other.y - self.y * other.x + self.z * other.w,
+            self.w * other.w - self.x * other.x - self.y * other.y - self.z * other.z)
+    }
+}
+
+impl<T: Float + fmt::Display> fmt::Display for Quaternion<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "(")?;
+        fmt::Display::fmt(&self.x, f)?;
+        write!(f, ", ")?;
+        fmt::Display::fmt(&self.y, f)?;
+        write!(f, ", ")?;
+        fmt::Display::fmt(&self.z, f)?;
+        write!(f, ", ")?;
+        fmt::Display::fmt(&self.w, f)?;
+        write!(f, ")")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use float_cmp::approx_eq;
+
+    fn assert_vectors_equal(a: Vector3<f64>, b: Vector3<f64>) {
+        assert!( approx_eq!(f64, (a - b).length(), 0.0, epsilon = 0.0001) );
+    }
+
+    #[test]
+    fn test_rotate_vector() {
+        // Arrange
+        let rotation = Quaternion::from_axis_angle(Vector3::new(0.0, 0.0, 1.0), core::f64::consts::FRAC_PI_2);
+
+        // Act
+        let rotated = rotation.rotate_vector(Vector3::new(1.0, 0.0, 0.0));
+
+        // Assert
+        assert_vectors_equal(rotated, Vector3::new(0.0, 1.0, 0.0));
+    }
+
+    #[test]
+    fn test_composition_matches_matrices() {
+        // Arrange
+        let a = Quaternion::from_axis_angle(Vector3::new(0.0, 0.0, 1.0), 0.4);
+        let b = Quaternion::from_axis_angle(Vector3::new(0.6, 0.8, 0.0), -1.3);
+        let vector = Vector3::new(1.0, 2.0, 3.0);
+
+        // Act
+        let composed = (a * b).rotate_vector(vector);
+        let matrix = a.to_matrix().mul(b.to_matrix());
+
+        // Assert
+        assert_vectors_equal(composed, a.rotate_vector(b.rotate_vector(vector)));
+        assert_vectors_equal(composed, matrix.mul(vector));
+        assert_vectors_equal((a.conjugate() * a).rotate_vector(vector), vector);
+    }
+
+    #[test]
+    fn test_matrix_round_trip() {
+        // Arrange
+        let rotations = [
+            Quaternion::identity(),
+            Quaternion::from_axis_angle(Vector3::new(0.0, 1.0, 0.0), 0.3),
+            Quaternion::from_axis_angle(Vector3::new(1.0, 0.0, 0.0), core::f64::consts::PI),
+            Quaternion::from_axis_angle(Vector3::new(0.0, 0.6, 0.8), 3.0)];
+
+        for rotation in rotations.iter() {
+            // Act
+            let round_trip = Quaternion::from_rotation_matrix(&rotation.to_matrix());
+
+            // Assert
+            assert!( approx_eq!(f64, round_trip.dot_product(*rotation).abs(), 1.0, epsilon = 0.0001) );
+        }
+    }
+
+    #[test]
+    fn test_slerp() {
+        // Arrange
+        let axis = Vector3::new(0.0, 0.0, 1.0);
+        let a = Quaternion::from_axis_angle(axis, 0.2);
+        let b = Quaternion::from_axis_angle(axis, 1.4);
+
+        // Act
+        let halfway = a.slerp(b, 0.5);
+        let halfway_the_short_way = a.slerp(b.scale(-1.0), 0.5);
+
+        // Assert
+        let expected = Quaternion::from_axis_angle(axis, 0.8);
+        assert!( approx_eq!(f64, halfway.dot_product(expected), 1.0, epsilon = 0.0001) );
+        assert!( approx_eq!(f64, halfway_the_short_way.dot_product(expected).abs(), 1.0, epsilon = 0.0001) );
+        assert!( approx_eq!(f64, a.slerp(a, 0.5).dot_product(a), 1.0, epsilon = 0.0001) );
+    }
+
+    #[test]
+    fn test_display() {
+        // Act & Assert
+        assert_eq!(format!("{}", Quaternion::<f32>::identity()), "(0, 0, 0, 1)");
+    }
+}
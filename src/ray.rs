@@ -0,0 +1,55 @@
+use core::fmt;
+
+use crate::vector3::Vector3;
+use crate::float_trait::Float;
+
+// A ray starts at its origin and extends infinitely in the direction of "direction".
+// The direction does not have to be normalized, but distances along the ray are then measured in multiples of its length.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Ray<T> where T: Float {
+    pub origin: Vector3<T>,
+    pub direction: Vector3<T>
+}
+
+impl<T: Float> Ray<T> {
+    pub fn new(origin: Vector3<T>, direction: Vector3<T>) -> Ray<T> {
+        Ray {
+            origin,
+            direction
+        }
+    }
+
+    pub fn point_at(self, distance: T) -> Vector3<T> {
+        self.origin + self.direction.scalar_multiplication(distance)
+    }
+}
+
+impl<T: Float + fmt::Display> fmt::Display for Ray<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "origin ")?;
+        fmt::Display::fmt(&self.origin, f)?;
+        write!(f, ", direction ")?;
+        fmt::Display::fmt(&self.direction, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_point_at() {
+        // Arrange
+        let ray = Ray::new(Vector3::<f64>::new(1.0, 2.0, 3.0), Vector3::<f64>::new(0.0, 0.0, -1.0));
+
+        // Act
+        let point = ray.point_at(2.0);
+
+        // Assert
+        assert_eq!(point.x, 1.0);
+        assert_eq!(point.y, 2.0);
+        assert_eq!(point.z, 1.0);
+    }
+}
@@ -0,0 +1,167 @@
+use core::ops::Mul;
+
+use crate::vector3::Vector3;
+use crate::float_trait::Float;
+use crate::matrix::Matrix;
+use crate::eigen::covariance;
+use crate::affine::Affine3;
+
+// A rotation followed by a translation. It moves objects around without changing their shape or size,
+// which is what is left when aligning two measurements of the same rigid object.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct RigidTransform<T> where T: Float {
+    pub rotation: Matrix<T, 3, 3>,
+    pub translation: Vector3<T>
+}
+
+impl<T: Float> RigidTransform<T> {
+    pub fn new(rotation: Matrix<T, 3, 3>, translation: Vector3<T>) -> RigidTransform<T> {
+        RigidTransform {
+            rotation,
+            translation
+        }
+    }
+
+    pub fn identity() -> RigidTransform<T> {
+        RigidTransform::new(Matrix::identity(), Vector3::new(T::zero(), T::zero(), T::zero()))
+    }
+
+    pub fn transform_point(&self, point: Vector3<T>) -> Vector3<T> {
+        self.transform_vector(point) + self.translation
+    }
+
+    pub fn transform_vector(&self, vector: Vector3<T>) -> Vector3<T> {
+        self.rotation.mul(vector)
+    }
+
+    // The inverse of a rotation is its transpose, so unlike a general matrix this never fails
+    pub fn inverse(&self) -> RigidTransform<T> {
+        let rotation = self.rotation.transpose();
+        let translation = rotation.mul(self.translation);
+
+        RigidTransform::new(rotation, Vector3::new(T::zero(), T::zero(), T::zero()) - translation)
+    }
+
+    // The 4x4 matrix that applies the rotation and then the translation, for use with the other matrices in the crate
+    pub fn to_matrix(&self) -> Matrix<T, 4, 4> {
+        Affine3::from(*self).to_matrix()
+    }
+}
+
+// Composes two transforms, so that "a * b" applies b first and then a, like matrix multiplication
+impl<T: Float> Mul for RigidTransform<T> {
+    type Output = RigidTransform<T>;
+
+    fn mul(self, other: RigidTransform<T>) -> RigidTransform<T> {
+        RigidTransform::new(self.rotation.mul(other.rotation), self.transform_point(other.translation))
+    }
+}
+
+// Finds the rigid transform that moves the points in "from" as closely as possible onto the corresponding points in "to",
+// minimizing the sum of the squared distances, using the Kabsch algorithm.
+// Both sets are moved so that their means are at the origin, and the rotation is found from the SVD of their cross-covariance matrix.
+// The rotation is always a proper rotation. Mirrored point sets are aligned as well as a rotation allows, rather than by reflecting them.
+// Returns None if there are no points. Panics if the two slices have different lengths, since the points must correspond one to one.
+pub fn kabsch<T: Float>(from: &[Vector3<T>], to: &[Vector3<T>]) -> Option<RigidTransform<T>> {
+    if from.len() != to.len() {
+        panic!("Kabsch needs corresponding points, but got {} and {} points!", from.len(), to.len());
+    }
+
+    let (from_mean, _) = covariance(from)?;
+    let (to_mean, _) = covariance(to)?;
+
+    // The cross-covariance matrix, the sum of (to - to_mean)(from - from_mean)^T
+    let mut cross_covariance = Matrix::from_cols([[T::zero(); 3]; 3]);
+
+    for (from_point, to_point) in from.iter().zip(to) {
+        let from_offset = *from_point - from_mean;
+        let to_offset = *to_point - to_mean;
+
+        cross_covariance = cross_covariance + to_offset.mul(from_offset.transpose());
+    }
+
+    // With cross_covariance = U S V^T, the best rotation is U V^T. The SVD keeps U and V proper rotations,
+    // which gives the reflection-corrected rotation that the textbook algorithm computes with an extra sign check.
+    let svd = cross_covariance.svd();
+    let rotation = svd.u.mul(svd.v.transpose());
+    let rotated_mean = rotation.mul(from_mean);
+
+    Some(RigidTransform::new(rotation, to_mean - rotated_mean))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix::Vector;
+    use float_cmp::approx_eq;
+
+    fn rotation_around_z(angle: f64) -> Matrix<f64, 3, 3> {
+        Matrix::from_rows([
+            [angle.cos(), -angle.sin(), 0.0],
+            [angle.sin(), angle.cos(), 0.0],
+            [0.0, 0.0, 1.0]])
+    }
+
+    fn assert_vectors_equal(a: Vector3<f64>, b: Vector3<f64>) {
+        assert!( approx_eq!(f64, (a - b).length(), 0.0, epsilon = 0.0001) );
+    }
+
+    #[test]
+    fn test_transform_and_inverse() {
+        // Arrange
+        let transform = RigidTransform::new(rotation_around_z(core::f64::consts::FRAC_PI_2), Vector3::new(1.0, 2.0, 3.0));
+        let point = Vector3::new(1.0, 0.0, 0.0);
+
+        // Act
+        let transformed_point = transform.transform_point(point);
+        let round_trip = (transform.inverse() * transform).transform_point(point);
+        let matrix_point = transform.to_matrix().mul(Vector::from_array([1.0, 0.0, 0.0, 1.0]));
+
+        // Assert
+        assert_vectors_equal(transformed_point, Vector3::new(1.0, 3.0, 3.0));
+        assert_vectors_equal(round_trip, point);
+        assert_vectors_equal(Vector3::new(matrix_point[[0, 0]], matrix_point[[1, 0]], matrix_point[[2, 0]]), transformed_point);
+    }
+
+    #[test]
+    fn test_kabsch() {
+        // Arrange
+        let expected = RigidTransform::new(rotation_around_z(0.7), Vector3::new(5.0, -1.0, 2.0));
+        let from = [
+            Vector3::new(0.0, 0.0, 0.0),
+            Vector3::new(1.0, 0.0, 0.0),
+            Vector3::new(0.0, 2.0, 0.0),
+            Vector3::new(0.0, 0.0, 3.0),
+            Vector3::new(1.0, 1.0, 1.0)];
+        let to: Vec<Vector3<f64>> = from.iter().map(|point| expected.transform_point(*point)).collect();
+
+        // Act
+        let transform = kabsch(&from, &to).unwrap();
+
+        // Assert
+        assert_vectors_equal(transform.translation, expected.translation);
+
+        for (from_point, to_point) in from.iter().zip(&to) {
+            assert_vectors_equal(transform.transform_point(*from_point), *to_point);
+        }
+
+        assert!( approx_eq!(f64, transform.rotation.determinant(), 1.0, epsilon = 0.0001) );
+    }
+
+    #[test]
+    fn test_kabsch_never_reflects() {
+        // Arrange
+        // The second set is the first one mirrored in the xy plane, which no rotation can reproduce exactly
+        let from = [Vector3::new(1.0, 0.0, 0.0), Vector3::new(0.0, 1.0, 0.0), Vector3::new(0.0, 0.0, 1.0), Vector3::new(1.0, 1.0, 2.0)];
+        let to: Vec<Vector3<f64>> = from.iter().map(|point| Vector3::new(point.x, point.y, -point.z)).collect();
+
+        // Act
+        let transform = kabsch(&from, &to).unwrap();
+
+        // Assert
+        assert!( approx_eq!(f64, transform.rotation.determinant(), 1.0, epsilon = 0.0001) );
+        assert!(kabsch::<f64>(&[], &[]).is_none());
+    }
+}
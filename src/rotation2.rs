@@ -0,0 +1,146 @@
+use core::ops::Mul;
+
+use crate::vector2::Vector2;
+use crate::float_trait::Float;
+use crate::matrix::Matrix;
+
+// A 2D rotation stored as the unit complex number cos(angle) + i sin(angle).
+// Rotating a vector is then a complex multiplication, which needs no sine or cosine, so compute the rotation once and apply it to many vectors.
+// Composing two rotations multiplies them, which adds their angles.
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Rotation2<T> where T: Float {
+    pub cos: T,
+    pub sin: T
+}
+
+impl<T: Float> Rotation2<T> {
+    // The angle is in radians, counterclockwise
+    pub fn new(angle: T) -> Rotation2<T> {
+        Rotation2 {
+            cos: angle.cos(),
+            sin: angle.sin()
+        }
+    }
+
+    pub fn identity() -> Rotation2<T> {
+        Rotation2 {
+            cos: T::one(),
+            sin: T::zero()
+        }
+    }
+
+    // The rotation that turns the direction of "from" into the direction of "to". Neither needs to have unit length, but both must be non-zero.
+    pub fn between(from: Vector2<T>, to: Vector2<T>) -> Rotation2<T> {
+        Rotation2 {
+            cos: from.dot_product(to),
+            sin: from.perp_dot(to)
+        }.normalize()
+    }
+
+    // The angle in radians, between -pi and pi
+    pub fn angle(self) -> T {
+        self.sin.atan2(self.cos)
+    }
+
+    // Composing many rotations slowly lets the length drift away from 1 through rounding, so normalize every now and then
+    pub fn normalize(self) -> Rotation2<T> {
+        let length = (self.cos * self.cos + self.sin * self.sin).sqrt();
+
+        Rotation2 {
+            cos: self.cos / length,
+            sin: self.sin / length
+        }
+    }
+
+    pub fn inverse(self) -> Rotation2<T> {
+        Rotation2 {
+            cos: self.cos,
+            sin: -self.sin
+        }
+    }
+
+    pub fn rotate_vector(self, vector: Vector2<T>) -> Vector2<T> {
+        Vector2::new(vector.x * self.cos - vector.y * self.sin, vector.x * self.sin + vector.y * self.cos)
+    }
+
+    pub fn to_matrix(self) -> Matrix<T, 2, 2> {
+        Matrix::from_rows([
+            [self.cos, -self.sin],
+            [self.sin, self.cos]])
+    }
+
+    // Interpolates along the shorter way around the circle, at a constant angular speed
+    pub fn slerp(self, other: Rotation2<T>, t: T) -> Rotation2<T> {
+        let difference = self.inverse() * other;
+
+        self * Rotation2::new(difference.angle() * t)
+    }
+}
+
+// Composes two rotations, so that "a * b" rotates by b first and then by a. For 2D rotations the order does not matter.
+impl<T: Float> Mul for Rotation2<T> {
+    type Output = Rotation2<T>;
+
+    fn mul(self, other: Rotation2<T>) -> Rotation2<T> {
+        Rotation2 {
+            cos: self.cos * other.cos - self.sin * other.sin,
+            sin: self.sin * other.cos + self.cos * other.sin
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use float_cmp::approx_eq;
+
+    #[test]
+    fn test_rotate_vector() {
+        // Arrange
+        let rotation = Rotation2::new(0.6);
+        let vector = Vector2::new(3.0, -1.0);
+
+        // Act
+        let rotated = rotation.rotate_vector(vector);
+        let matrix_rotated = rotation.to_matrix().mul(vector);
+
+        // Assert
+        let expected = vector.rotate(0.6);
+        assert!( approx_eq!(f64, (rotated - expected).length(), 0.0, epsilon = 0.0001) );
+        assert!( approx_eq!(f64, (matrix_rotated - expected).length(), 0.0, epsilon = 0.0001) );
+        assert!( approx_eq!(f64, (rotation.inverse().rotate_vector(rotated) - vector).length(), 0.0, epsilon = 0.0001) );
+    }
+
+    #[test]
+    fn test_composition_and_between() {
+        // Arrange
+        let a = Rotation2::new(2.5);
+        let b = Rotation2::new(1.5);
+
+        // Act
+        let composed = a * b;
+        let between = Rotation2::between(Vector2::new(2.0, 0.0), Vector2::new(0.0, -3.0));
+
+        // Assert
+        // 2.5 + 1.5 is more than pi, so the angle wraps around to the negative side
+        assert!( approx_eq!(f64, composed.angle(), 4.0 - 2.0 * core::f64::consts::PI, epsilon = 0.0001) );
+        assert!( approx_eq!(f64, between.angle(), -core::f64::consts::FRAC_PI_2, epsilon = 0.0001) );
+    }
+
+    #[test]
+    fn test_slerp() {
+        // Arrange
+        // 170 degrees to -170 degrees is 20 degrees the short way, across the negative x axis
+        let a = Rotation2::new(170.0_f64.to_radians());
+        let b = Rotation2::new(-170.0_f64.to_radians());
+
+        // Act
+        let halfway = a.slerp(b, 0.5);
+
+        // Assert
+        assert!( approx_eq!(f64, halfway.angle().abs(), core::f64::consts::PI, epsilon = 0.0001) );
+        assert!( approx_eq!(f64, a.slerp(b, 1.0).angle(), b.angle(), epsilon = 0.0001) );
+    }
+}
@@ -0,0 +1,155 @@
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::{Add, Sub, Mul};
+
+use serde::{Serialize, Serializer, Deserialize, Deserializer};
+use serde::ser::SerializeTuple;
+use serde::de::{Visitor, SeqAccess, Error};
+
+use crate::matrix::Matrix;
+
+// By default a matrix is written as a flat array of all its elements in column-major order, which is the order it is stored in,
+// and the order that OpenGL expects when uploading it. A Matrix4x4 becomes an array of 16 numbers,
+// and a Vector3 becomes [1.0, 2.0, 3.0] in JSON instead of {"x": 1.0, "y": 2.0, "z": 3.0}.
+impl<T, const R: usize, const C: usize> Serialize for Matrix<T, R, C> where T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy + Serialize {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut tuple = serializer.serialize_tuple(R * C)?;
+
+        for element in Matrix::as_flat_slice(core::slice::from_ref(self)) {
+            tuple.serialize_element(element)?;
+        }
+
+        tuple.end()
+    }
+}
+
+struct MatrixVisitor<T, const R: usize, const C: usize>(PhantomData<T>);
+
+impl<'de, T, const R: usize, const C: usize> Visitor<'de> for MatrixVisitor<T, R, C> where T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy + Default + Deserialize<'de> {
+    type Value = Matrix<T, R, C>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "a sequence of {} elements", R * C)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut matrix = Matrix::default();
+
+        for index in 0..R * C {
+            matrix[[index % R, index / R]] = seq.next_element()?.ok_or_else(|| A::Error::invalid_length(index, &self))?;
+        }
+
+        Ok(matrix)
+    }
+}
+
+impl<'de, T, const R: usize, const C: usize> Deserialize<'de> for Matrix<T, R, C> where T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy + Default + Deserialize<'de> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_tuple(R * C, MatrixVisitor(PhantomData))
+    }
+}
+
+// Writes a matrix as four nested arrays, one per row, which is easier to read and edit by hand.
+// Use it on a field with: #[serde(with = "linear_beaglebra::serde_support::row_major")]
+pub mod row_major {
+    use serde::{Serialize, Serializer, Deserialize, Deserializer};
+
+    use crate::matrix4x4::Matrix4x4;
+
+    pub fn serialize<S: Serializer>(matrix: &Matrix4x4, serializer: S) -> Result<S::Ok, S::Error> {
+        // The rows of a matrix are the columns of its transpose
+        matrix.transpose().to_cols_array_2d().serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Matrix4x4, D::Error> {
+        Ok(Matrix4x4::from_cols_array_2d(<[[f32; 4]; 4]>::deserialize(deserializer)?).transpose())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vector2::Vector2;
+    use crate::vector3::Vector3;
+    use crate::vector4::Vector4;
+    use crate::matrix4x4::Matrix4x4;
+    use crate::matrix3x3::Matrix3x3;
+
+    #[test]
+    fn test_vector_round_trip() {
+        // Arrange
+        let vector_a = Vector3::<f32>::new(1.0, -2.5, 3.0);
+
+        // Act
+        let json = serde_json::to_string(&vector_a).unwrap();
+        let deserialized_vector: Vector3<f32> = serde_json::from_str(&json).unwrap();
+
+        // Assert
+        assert_eq!(json, "[1.0,-2.5,3.0]");
+        assert_eq!(deserialized_vector, vector_a);
+    }
+
+    #[test]
+    fn test_vector_wrong_length() {
+        // Act
+        let too_short = serde_json::from_str::<Vector4<i32>>("[1, 2, 3]");
+        let too_long = serde_json::from_str::<Vector2<i32>>("[1, 2, 3]");
+
+        // Assert
+        assert!(too_short.is_err());
+        assert!(too_long.is_err());
+    }
+
+    #[test]
+    fn test_matrix_round_trip() {
+        // Arrange
+        let matrix_a = Matrix4x4::identity().translate(Vector2::new(5.0, 7.0));
+
+        // Act
+        let json = serde_json::to_string(&matrix_a).unwrap();
+        let deserialized_matrix: Matrix4x4 = serde_json::from_str(&json).unwrap();
+
+        // Assert
+        // The translation is in the last column, so it comes last in column-major order
+        assert_eq!(json, "[1.0,0.0,0.0,0.0,0.0,1.0,0.0,0.0,0.0,0.0,1.0,0.0,5.0,7.0,0.0,1.0]");
+        assert!(deserialized_matrix == matrix_a);
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct Transform {
+        #[serde(with = "row_major")]
+        matrix: Matrix4x4
+    }
+
+    #[test]
+    fn test_matrix_row_major_round_trip() {
+        // Arrange
+        let transform = Transform { matrix: Matrix4x4::identity().translate(Vector2::new(5.0, 7.0)) };
+
+        // Act
+        let json = serde_json::to_string(&transform).unwrap();
+        let deserialized_transform: Transform = serde_json::from_str(&json).unwrap();
+
+        // Assert
+        assert_eq!(json, r#"{"matrix":[[1.0,0.0,0.0,5.0],[0.0,1.0,0.0,7.0],[0.0,0.0,1.0,0.0],[0.0,0.0,0.0,1.0]]}"#);
+        assert!(deserialized_transform.matrix == transform.matrix);
+    }
+
+    #[test]
+    fn test_generic_matrix_round_trip() {
+        // Arrange
+        let matrix_a = Matrix3x3::from_rows_array([1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+        let matrix_b = Matrix::<i32, 2, 3>::from_rows([[1, 2, 3], [4, 5, 6]]);
+
+        // Act
+        let json_a = serde_json::to_string(&matrix_a).unwrap();
+        let json_b = serde_json::to_string(&matrix_b).unwrap();
+
+        // Assert
+        assert_eq!(json_a, "[1.0,4.0,7.0,2.0,5.0,8.0,3.0,6.0,9.0]");
+        assert_eq!(json_b, "[1,4,2,5,3,6]");
+        assert_eq!(serde_json::from_str::<Matrix3x3>(&json_a).unwrap(), matrix_a);
+        assert_eq!(serde_json::from_str::<Matrix<i32, 2, 3>>(&json_b).unwrap(), matrix_b);
+        assert!(serde_json::from_str::<Matrix<i32, 2, 3>>("[1, 2, 3]").is_err());
+    }
+}
@@ -0,0 +1,355 @@
+use core::fmt;
+
+use crate::vector3::Vector3;
+use crate::matrix4x4::Matrix4x4;
+use crate::matrix3x3::Matrix3x3;
+use crate::float_trait::Float;
+
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Sphere<T> where T: Float {
+    pub center: Vector3<T>,
+    pub radius: T
+}
+
+impl<T: Float> Sphere<T> {
+    pub fn new(center: Vector3<T>, radius: T) -> Sphere<T> {
+        Sphere {
+            center,
+            radius
+        }
+    }
+
+    // Ritter's algorithm. It is fast (two passes over the points) but only approximates the minimal sphere,
+    // usually being 5-20% larger than it. Returns None if there are no points.
+    pub fn from_points_ritter(points: &[Vector3<T>]) -> Option<Sphere<T>> {
+        let first = *points.first()?;
+
+        // Find two points far apart, and use the sphere spanning them as the initial guess
+        let far_point_a = farthest_point(points, first);
+        let far_point_b = farthest_point(points, far_point_a);
+
+        let mut sphere = Sphere::from_two_points(far_point_a, far_point_b);
+
+        // Grow the sphere just enough to include any point left outside of it
+        for point in points {
+            let distance = (*point - sphere.center).length();
+
+            if distance > sphere.radius {
+                let new_radius = (sphere.radius + distance) / two();
+                let shift = (new_radius - sphere.radius) / distance;
+
+                sphere.center = sphere.center + (*point - sphere.center).scalar_multiplication(shift);
+                sphere.radius = new_radius;
+            }
+        }
+
+        Some(sphere)
+    }
+
+    // Computes the smallest enclosing sphere using Welzl's algorithm, written iteratively instead of recursively.
+    // Each level of nesting fixes one more point that must lie on the boundary of the sphere, up to four points.
+    // The expected running time is linear if the points are in random order, so shuffle them first if they are sorted in some way.
+    // Returns None if there are no points.
+    pub fn minimal_from_points(points: &[Vector3<T>]) -> Option<Sphere<T>> {
+        let first = *points.first()?;
+
+        let mut sphere = Sphere::new(first, T::zero());
+
+        for i in 1..points.len() {
+            if sphere.contains_point_with_tolerance(points[i]) {
+                continue;
+            }
+
+            sphere = Sphere::new(points[i], T::zero());
+
+            for j in 0..i {
+                if sphere.contains_point_with_tolerance(points[j]) {
+                    continue;
+                }
+
+                sphere = Sphere::from_two_points(points[i], points[j]);
+
+                for k in 0..j {
+                    if sphere.contains_point_with_tolerance(points[k]) {
+                        continue;
+                    }
+
+                    sphere = Sphere::from_three_points(points[i], points[j], points[k]);
+
+                    for l in 0..k {
+                        if sphere.contains_point_with_tolerance(points[l]) {
+                            continue;
+                        }
+
+                        sphere = Sphere::from_four_points(points[i], points[j], points[k], points[l]);
+                    }
+                }
+            }
+        }
+
+        Some(sphere)
+    }
+
+    fn from_two_points(a: Vector3<T>, b: Vector3<T>) -> Sphere<T> {
+        Sphere::new((a + b).scalar_multiplication(T::one() / two()), (b - a).length() / two())
+    }
+
+    // The smallest sphere with all three points on its surface, which is centered on the triangle's circumcenter.
+    fn from_three_points(a: Vector3<T>, b: Vector3<T>, c: Vector3<T>) -> Sphere<T> {
+        let ab = b - a;
+        let ac = c - a;
+        let normal = ab.cross_product(ac);
+        let denominator = two::<T>() * normal.dot_product(normal);
+
+        if denominator <= T::epsilon() {
+            // The points are collinear, so the sphere spanning the two points farthest apart encloses all of them
+            return largest(&[Sphere::from_two_points(a, b), Sphere::from_two_points(a, c), Sphere::from_two_points(b, c)]);
+        }
+
+        let offset = (normal.cross_product(ab).scalar_multiplication(ac.dot_product(ac))
+            + ac.cross_product(normal).scalar_multiplication(ab.dot_product(ab)))
+            .scalar_multiplication(T::one() / denominator);
+
+        Sphere::new(a + offset, offset.length())
+    }
+
+    // The sphere with all four points on its surface, centered on the tetrahedron's circumcenter.
+    fn from_four_points(a: Vector3<T>, b: Vector3<T>, c: Vector3<T>, d: Vector3<T>) -> Sphere<T> {
+        let u = b - a;
+        let v = c - a;
+        let w = d - a;
+        let denominator = two::<T>() * u.dot_product(v.cross_product(w));
+
+        if denominator.abs() <= T::epsilon() {
+            // The points are coplanar. Fall back to the smallest sphere through three of them that contains the fourth.
+            let candidates = [
+                (Sphere::from_three_points(a, b, c), d),
+                (Sphere::from_three_points(a, b, d), c),
+                (Sphere::from_three_points(a, c, d), b),
+                (Sphere::from_three_points(b, c, d), a)
+            ];
+
+            let mut best: Option<Sphere<T>> = None;
+
+            for (sphere, remaining_point) in candidates.iter() {
+                if sphere.contains_point_with_tolerance(*remaining_point) && best.is_none_or(|best| sphere.radius < best.radius) {
+                    best = Some(*sphere);
+                }
+            }
+
+            return best.unwrap_or_else(|| largest(&[candidates[0].0, candidates[1].0, candidates[2].0, candidates[3].0]));
+        }
+
+        let offset = (v.cross_product(w).scalar_multiplication(u.dot_product(u))
+            + w.cross_product(u).scalar_multiplication(v.dot_product(v))
+            + u.cross_product(v).scalar_multiplication(w.dot_product(w)))
+            .scalar_multiplication(T::one() / denominator);
+
+        Sphere::new(a + offset, offset.length())
+    }
+
+    pub fn contains_point(self, point: Vector3<T>) -> bool {
+        let offset = point - self.center;
+
+        offset.dot_product(offset) <= self.radius * self.radius
+    }
+
+    // Rounding errors can leave points that are exactly on the boundary slightly outside of the sphere.
+    fn contains_point_with_tolerance(self, point: Vector3<T>) -> bool {
+        let tolerance = T::epsilon().sqrt() * (T::one() + self.radius);
+
+        (point - self.center).length() <= self.radius + tolerance
+    }
+
+    pub fn intersects(self, other: Sphere<T>) -> bool {
+        let offset = other.center - self.center;
+        let radius_sum = self.radius + other.radius;
+
+        offset.dot_product(offset) <= radius_sum * radius_sum
+    }
+}
+
+impl Sphere<f32> {
+    // The radius is scaled by the largest amount the matrix stretches any direction, which is its largest singular value,
+    // so the result still encloses the transformed sphere even if the matrix scales non-uniformly or shears.
+    // The lengths of the transformed axes are not enough on their own, since a shear stretches some diagonal direction more than any axis.
+    pub fn transform(self, matrix: &Matrix4x4) -> Sphere<f32> {
+        let scale_x = matrix.transform_vector(Vector3::new(1.0, 0.0, 0.0)).length();
+        let scale_y = matrix.transform_vector(Vector3::new(0.0, 1.0, 0.0)).length();
+        let scale_z = matrix.transform_vector(Vector3::new(0.0, 0.0, 1.0)).length();
+
+        // The largest singular value is never smaller than the axis lengths. Taking the maximum anyway guards against its rounding errors,
+        // and keeps the result exact for a plain scale.
+        let largest_singular_value = Matrix3x3::from_matrix4x4(matrix).svd().singular_values[0];
+        let scale = largest_singular_value.max(scale_x).max(scale_y).max(scale_z);
+
+        Sphere::new(matrix.transform_point(self.center), self.radius * scale)
+    }
+}
+
+fn two<T: Float>() -> T {
+    T::one() + T::one()
+}
+
+fn farthest_point<T: Float>(points: &[Vector3<T>], from: Vector3<T>) -> Vector3<T> {
+    let mut farthest = from;
+    let mut farthest_distance = T::zero();
+
+    for point in points {
+        let offset = *point - from;
+        let distance = offset.dot_product(offset);
+
+        if distance > farthest_distance {
+            farthest = *point;
+            farthest_distance = distance;
+        }
+    }
+
+    farthest
+}
+
+fn largest<T: Float>(spheres: &[Sphere<T>]) -> Sphere<T> {
+    let mut largest = spheres[0];
+
+    for sphere in &spheres[1..] {
+        if sphere.radius > largest.radius {
+            largest = *sphere;
+        }
+    }
+
+    largest
+}
+
+impl<T: Float + fmt::Display> fmt::Display for Sphere<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "center ")?;
+        fmt::Display::fmt(&self.center, f)?;
+        write!(f, ", radius ")?;
+        fmt::Display::fmt(&self.radius, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use float_cmp::approx_eq;
+
+    fn test_points() -> [Vector3<f64>; 8] {
+        [
+            Vector3::new(1.0, 0.0, 0.0),
+            Vector3::new(-1.0, 0.0, 0.0),
+            Vector3::new(0.0, 1.0, 0.0),
+            Vector3::new(0.0, -1.0, 0.0),
+            Vector3::new(0.0, 0.0, 1.0),
+            Vector3::new(0.0, 0.0, -1.0),
+            Vector3::new(0.2, 0.3, 0.1),
+            Vector3::new(-0.5, 0.5, 0.5)
+        ]
+    }
+
+    #[test]
+    fn test_from_points_ritter() {
+        // Arrange
+        let points = test_points();
+
+        // Act
+        let sphere = Sphere::from_points_ritter(&points).unwrap();
+
+        // Assert
+        for point in points.iter() {
+            assert!(sphere.contains_point_with_tolerance(*point));
+        }
+        assert!(sphere.radius >= 1.0);
+    }
+
+    #[test]
+    fn test_minimal_from_points() {
+        // Arrange
+        let points = test_points();
+
+        // Act
+        let sphere = Sphere::minimal_from_points(&points).unwrap();
+
+        // Assert
+        for point in points.iter() {
+            assert!(sphere.contains_point_with_tolerance(*point));
+        }
+        assert!( approx_eq!(f64, sphere.radius, 1.0, epsilon = 0.0001) );
+        assert!( approx_eq!(f64, sphere.center.length(), 0.0, epsilon = 0.0001) );
+    }
+
+    #[test]
+    fn test_minimal_from_triangle_points() {
+        // Arrange
+        // An equilateral triangle with its vertices on the unit circle
+        let points = [
+            Vector3::<f64>::new(1.0, 0.0, 0.0),
+            Vector3::<f64>::new(-0.5, 0.75_f64.sqrt(), 0.0),
+            Vector3::<f64>::new(-0.5, -(0.75_f64.sqrt()), 0.0)
+        ];
+
+        // Act
+        let sphere = Sphere::minimal_from_points(&points).unwrap();
+
+        // Assert
+        assert!( approx_eq!(f64, sphere.radius, 1.0, epsilon = 0.0001) );
+        assert!( approx_eq!(f64, sphere.center.length(), 0.0, epsilon = 0.0001) );
+    }
+
+    #[test]
+    fn test_from_no_points() {
+        // Act & Assert
+        assert!(Sphere::<f64>::from_points_ritter(&[]).is_none());
+        assert!(Sphere::<f64>::minimal_from_points(&[]).is_none());
+    }
+
+    #[test]
+    fn test_intersects() {
+        // Arrange
+        let sphere_a = Sphere::new(Vector3::<f64>::new(0.0, 0.0, 0.0), 1.0);
+        let sphere_b = Sphere::new(Vector3::<f64>::new(1.5, 0.0, 0.0), 1.0);
+        let sphere_c = Sphere::new(Vector3::<f64>::new(3.0, 0.0, 0.0), 0.5);
+
+        // Act & Assert
+        assert!(sphere_a.intersects(sphere_b));
+        assert!(!sphere_a.intersects(sphere_c));
+    }
+
+    #[test]
+    fn test_transform() {
+        // Arrange
+        let sphere = Sphere::new(Vector3::<f32>::new(1.0, 0.0, 0.0), 1.0);
+        let matrix = Matrix4x4::identity().scale(2.0, 3.0, 1.0);
+
+        // Act
+        let transformed_sphere = sphere.transform(&matrix);
+
+        // Assert
+        assert_eq!(transformed_sphere.center.x, 2.0);
+        assert_eq!(transformed_sphere.radius, 3.0);
+    }
+
+    #[test]
+    fn test_transform_with_shear() {
+        // Arrange
+        // The shear maps (1, 1, 0) / sqrt(2) to (2, 1, 0) / sqrt(2), which is longer than either transformed axis.
+        // The direction it stretches the most is slightly steeper still.
+        let sphere = Sphere::new(Vector3::<f32>::new(0.0, 0.0, 0.0), 1.0);
+        let matrix = Matrix4x4::new(
+            1.0, 1.0, 0.0, 0.0,
+            0.0, 1.0, 0.0, 0.0,
+            0.0, 0.0, 1.0, 0.0,
+            0.0, 0.0, 0.0, 1.0);
+        let farthest_point = matrix.transform_point(Vector3::new(0.5257311, 0.8506508, 0.0));
+
+        // Act
+        let transformed_sphere = sphere.transform(&matrix);
+
+        // Assert
+        // The largest singular value of the shear is the golden ratio
+        assert!( approx_eq!(f32, transformed_sphere.radius, 1.618034, epsilon = 0.0001) );
+        assert!(farthest_point.length() <= transformed_sphere.radius + 0.0001);
+    }
+}
@@ -1,6 +1,8 @@
 /*
     Traits are collections of methods defined for an unknown type "Self".
 */
+use crate::math;
+
 pub trait Sqrt {
     // "Self" refers to the implementor type.
     fn sqrt(self) -> Self;
@@ -9,12 +11,12 @@ pub trait Sqrt {
 // Implement Sqrt trait for primitive Rust types
 impl Sqrt for f32 {
     fn sqrt(self) -> Self {
-        self.sqrt()
+        math::sqrtf(self)
     }
 }
 
 impl Sqrt for f64 {
     fn sqrt(self) -> Self {
-        self.sqrt()
+        math::sqrt(self)
     }
 }
\ No newline at end of file
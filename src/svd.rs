@@ -0,0 +1,163 @@
+use crate::vector3::Vector3;
+use crate::float_trait::Float;
+use crate::matrix::Matrix;
+
+// The singular value decomposition of a 3x3 matrix A = U diag(singular_values) V^T.
+// Geometrically, any linear transformation is a rotation (V^T), followed by a scaling along the axes, followed by another rotation (U).
+//
+// U and V are always proper rotations, with a determinant of +1. The textbook SVD only promises orthogonal matrices, which may include a reflection,
+// but for graphics a reflection is rarely what is wanted. Instead, when A itself contains a reflection (its determinant is negative),
+// the reflection is moved into the last singular value, which is then negative.
+// The singular values are sorted by descending absolute value, so only the smallest one can be negative.
+#[derive(Copy, Clone, Debug)]
+pub struct Svd<T> where T: Float {
+    pub u: Matrix<T, 3, 3>,
+    pub singular_values: [T; 3],
+    pub v: Matrix<T, 3, 3>
+}
+
+impl<T: Float> Svd<T> {
+    // Multiplies the factors back together, mostly useful for checking the decomposition
+    pub fn recompose(&self) -> Matrix<T, 3, 3> {
+        let [s0, s1, s2] = self.singular_values;
+        let scale = Matrix::from_rows([[s0, T::zero(), T::zero()], [T::zero(), s1, T::zero()], [T::zero(), T::zero(), s2]]);
+
+        self.u.mul(scale).mul(self.v.transpose())
+    }
+}
+
+// Some unit vector perpendicular to the given unit vector.
+// Crossing with the coordinate axis that the vector is the least aligned with keeps the result far from zero.
+fn any_perpendicular<T: Float>(vector: Vector3<T>) -> Vector3<T> {
+    let axis = if vector.x.abs() <= vector.y.abs() && vector.x.abs() <= vector.z.abs() {
+        Vector3::new(T::one(), T::zero(), T::zero())
+    } else if vector.y.abs() <= vector.z.abs() {
+        Vector3::new(T::zero(), T::one(), T::zero())
+    } else {
+        Vector3::new(T::zero(), T::zero(), T::one())
+    };
+
+    let perpendicular = vector.cross_product(axis);
+    perpendicular.scalar_multiplication(T::one() / perpendicular.length())
+}
+
+impl<T: Float> Matrix<T, 3, 3> {
+    // V is found as the eigenvectors of A^T A, whose eigenvalues are the squared singular values.
+    // The columns of A V are then the columns of U scaled by the singular values, so U follows by normalizing them.
+    // When a singular value is zero, its column of U cannot be found that way, and any perpendicular direction is used instead.
+    // Squaring the matrix loses some precision for tiny singular values, which is not a problem for the well-conditioned matrices common in graphics.
+    pub fn svd(&self) -> Svd<T> {
+        let eigen = self.transpose().mul(*self).symmetric_eigen();
+        let v = eigen.eigenvectors;
+        let scaled_u = self.mul(v);
+
+        let columns: [Vector3<T>; 3] = core::array::from_fn(|column| scaled_u.column(column));
+        let lengths = [columns[0].length(), columns[1].length(), columns[2].length()];
+
+        // Anything this small compared to the largest singular value is only rounding noise
+        let tolerance = lengths[0] * T::epsilon() * T::from_f64(16.0);
+
+        let u0 = if lengths[0] > tolerance {
+            columns[0].scalar_multiplication(T::one() / lengths[0])
+        } else {
+            Vector3::new(T::one(), T::zero(), T::zero())
+        };
+
+        // Removing any part along u0 that has crept in through rounding keeps U orthogonal
+        let remainder = columns[1] - u0.scalar_multiplication(columns[1].dot_product(u0));
+        let remainder_length = remainder.length();
+
+        let u1 = if lengths[1] > tolerance && remainder_length > tolerance {
+            remainder.scalar_multiplication(T::one() / remainder_length)
+        } else {
+            any_perpendicular(u0)
+        };
+
+        // The last column completes the rotation, and the sign of the last singular value absorbs any reflection
+        let u2 = u0.cross_product(u1);
+
+        let u = Matrix::from_cols([
+            u0.to_array(),
+            u1.to_array(),
+            u2.to_array()]);
+
+        Svd {
+            u,
+            singular_values: [columns[0].dot_product(u0), columns[1].dot_product(u1), columns[2].dot_product(u2)],
+            v
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use float_cmp::approx_eq;
+
+    fn assert_matrices_equal(a: &Matrix<f64, 3, 3>, b: &Matrix<f64, 3, 3>) {
+        for row in 0..3 {
+            for column in 0..3 {
+                assert!( approx_eq!(f64, a[[row, column]], b[[row, column]], epsilon = 0.0001) );
+            }
+        }
+    }
+
+    #[test]
+    fn test_svd() {
+        // Arrange
+        let matrix = Matrix::<f64, 3, 3>::from_rows([
+            [3.0, 2.0, 2.0],
+            [2.0, 3.0, -2.0],
+            [1.0, 0.0, 4.0]]);
+
+        // Act
+        let svd = matrix.svd();
+
+        // Assert
+        assert_matrices_equal(&svd.recompose(), &matrix);
+        assert!( approx_eq!(f64, svd.u.determinant(), 1.0, epsilon = 0.0001) );
+        assert!( approx_eq!(f64, svd.v.determinant(), 1.0, epsilon = 0.0001) );
+        assert!(svd.singular_values[0] >= svd.singular_values[1]);
+        assert!(svd.singular_values[1] >= svd.singular_values[2].abs());
+        assert!( approx_eq!(f64, svd.singular_values.iter().product::<f64>(), matrix.determinant(), epsilon = 0.0001) );
+    }
+
+    #[test]
+    fn test_svd_of_reflection() {
+        // Arrange
+        let matrix = Matrix::<f64, 3, 3>::from_rows([
+            [2.0, 0.0, 0.0],
+            [0.0, -3.0, 0.0],
+            [0.0, 0.0, 1.0]]);
+
+        // Act
+        let svd = matrix.svd();
+
+        // Assert
+        assert_matrices_equal(&svd.recompose(), &matrix);
+        assert!( approx_eq!(f64, svd.u.determinant(), 1.0, epsilon = 0.0001) );
+        assert!( approx_eq!(f64, svd.v.determinant(), 1.0, epsilon = 0.0001) );
+        assert!( approx_eq!(f64, svd.singular_values[0], 3.0, epsilon = 0.0001) );
+        assert!( approx_eq!(f64, svd.singular_values[1], 2.0, epsilon = 0.0001) );
+        assert!( approx_eq!(f64, svd.singular_values[2], -1.0, epsilon = 0.0001) );
+    }
+
+    #[test]
+    fn test_svd_of_singular_matrix() {
+        // Arrange
+        // Projects everything onto the x axis
+        let matrix = Matrix::<f64, 3, 3>::from_rows([
+            [1.0, 2.0, 0.0],
+            [0.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0]]);
+
+        // Act
+        let svd = matrix.svd();
+
+        // Assert
+        assert_matrices_equal(&svd.recompose(), &matrix);
+        assert!( approx_eq!(f64, svd.u.determinant(), 1.0, epsilon = 0.0001) );
+        assert!( approx_eq!(f64, svd.singular_values[0], 5.0_f64.sqrt(), epsilon = 0.0001) );
+        assert!( approx_eq!(f64, svd.singular_values[1], 0.0, epsilon = 0.0001) );
+    }
+}
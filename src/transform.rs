@@ -0,0 +1,244 @@
+use crate::vector3::Vector3;
+use crate::float_trait::Float;
+use crate::matrix::Matrix;
+use crate::quaternion::Quaternion;
+use crate::affine::Affine3;
+use crate::interpolation::Lerp;
+
+// A scale, followed by a rotation, followed by a translation. This is how objects in a scene are usually placed,
+// since each part can be edited and animated on its own, and it is turned into a matrix only when needed for rendering.
+// The matrix is T * R * S, the same order as Matrix4x4::identity().translate(..).rotate(..).scale(..).
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Transform<T> where T: Float {
+    pub translation: Vector3<T>,
+    pub rotation: Quaternion<T>,
+    pub scale: Vector3<T>
+}
+
+impl<T: Float> Transform<T> {
+    pub fn new(translation: Vector3<T>, rotation: Quaternion<T>, scale: Vector3<T>) -> Transform<T> {
+        Transform {
+            translation,
+            rotation,
+            scale
+        }
+    }
+
+    pub fn identity() -> Transform<T> {
+        Transform::new(Vector3::new(T::zero(), T::zero(), T::zero()), Quaternion::identity(), Vector3::new(T::one(), T::one(), T::one()))
+    }
+
+    // Transforms a point, which is affected by translation
+    pub fn transform_point(&self, point: Vector3<T>) -> Vector3<T> {
+        self.transform_vector(point) + self.translation
+    }
+
+    // Transforms a direction, which is NOT affected by translation
+    pub fn transform_vector(&self, vector: Vector3<T>) -> Vector3<T> {
+        self.rotation.rotate_vector(component_mul(self.scale, vector))
+    }
+
+    // Places "child", given relative to this transform, into the space this transform is relative to, like "self * child" for matrices.
+    // This is how a local transform becomes a world transform, by composing it with the world transform of its parent.
+    // LEARN: A rotation between two non-uniform scales produces shear, which scale and rotation alone cannot express.
+    // The result is then only an approximation, so use to_matrix on both and multiply the matrices when that matters.
+    pub fn compose(&self, child: &Transform<T>) -> Transform<T> {
+        Transform::new(
+            self.transform_point(child.translation),
+            (self.rotation * child.rotation).normalize(),
+            component_mul(self.scale, child.scale))
+    }
+
+    // The transform that undoes this one. The inverse has to undo the rotation before the scale, which a Transform can only express
+    // when the scale is the same along every axis. Returns None if the scale is not uniform, in which case to_affine().inverse() gives the exact inverse,
+    // or if the scale is zero, since the object is then flattened and cannot be restored.
+    pub fn inverse(&self) -> Option<Transform<T>> {
+        let is_uniform = self.scale.x == self.scale.y && self.scale.y == self.scale.z;
+        if !is_uniform || self.scale.x == T::zero() {
+            return None;
+        }
+
+        let rotation = self.rotation.conjugate();
+        let scale = T::one() / self.scale.x;
+        let translation = rotation.rotate_vector(self.translation).scalar_multiplication(scale);
+
+        Some(Transform::new(Vector3::new(T::zero(), T::zero(), T::zero()) - translation, rotation, Vector3::new(scale, scale, scale)))
+    }
+
+    pub fn to_matrix(&self) -> Matrix<T, 4, 4> {
+        self.to_affine().to_matrix()
+    }
+
+    pub fn to_affine(&self) -> Affine3<T> {
+        let rotation = self.rotation.to_matrix();
+        let scale = [self.scale.x, self.scale.y, self.scale.z];
+
+        Affine3::new(Matrix::from_fn(|row, column| rotation[[row, column]] * scale[column]), self.translation)
+    }
+
+    // Translation and scale are interpolated linearly, and rotation along the shortest arc, which keeps the object from shrinking halfway through a turn
+    pub fn interpolate(&self, other: &Transform<T>, t: T) -> Transform<T> {
+        Transform::new(
+            self.translation.lerp(other.translation, t),
+            self.rotation.slerp(other.rotation, t),
+            self.scale.lerp(other.scale, t))
+    }
+}
+
+impl<T: Float> From<Transform<T>> for Affine3<T> {
+    fn from(transform: Transform<T>) -> Affine3<T> {
+        transform.to_affine()
+    }
+}
+
+fn component_mul<T: Float>(a: Vector3<T>, b: Vector3<T>) -> Vector3<T> {
+    Vector3::new(a.x * b.x, a.y * b.y, a.z * b.z)
+}
+
+// Computes the world transform of every node in a hierarchy from the local transforms, where "parents[i]" is the index of the parent of node i,
+// or None for a root. Parents must come before their children, which is the order a depth first or breadth first walk of a scene graph produces,
+// so every parent is finished before it is needed and a single pass is enough.
+// Panics if the slices have different lengths, or if a parent index is not smaller than the index of its child.
+pub fn propagate_transforms<T: Float>(local: &[Transform<T>], parents: &[Option<usize>], world: &mut [Transform<T>]) {
+    if local.len() != parents.len() || local.len() != world.len() {
+        panic!("Expected one parent and one world transform per local transform, but got {} local transforms, {} parents and {} world transforms!",
+            local.len(), parents.len(), world.len());
+    }
+
+    for index in 0..local.len() {
+        world[index] = match parents[index] {
+            Some(parent) if parent < index => world[parent].compose(&local[index]),
+            Some(parent) => panic!("Node {} has parent {}, but parents must come before their children!", index, parent),
+            None => local[index]
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix::Vector;
+    use float_cmp::approx_eq;
+
+    fn assert_vectors_equal(a: Vector3<f64>, b: Vector3<f64>) {
+        assert!( approx_eq!(f64, (a - b).length(), 0.0, epsilon = 0.0001) );
+    }
+
+    fn example_transform() -> Transform<f64> {
+        Transform::new(
+            Vector3::new(1.0, 2.0, 3.0),
+            Quaternion::from_axis_angle(Vector3::new(0.0, 0.6, 0.8), 0.9),
+            Vector3::new(2.0, 2.0, 2.0))
+    }
+
+    #[test]
+    fn test_transform_point_matches_matrix() {
+        // Arrange
+        let transform = Transform::new(
+            Vector3::new(1.0, 2.0, 3.0),
+            Quaternion::from_axis_angle(Vector3::new(0.0, 0.0, 1.0), core::f64::consts::FRAC_PI_2),
+            Vector3::new(2.0, 3.0, 4.0));
+        let point = Vector3::new(1.0, 1.0, 1.0);
+
+        // Act
+        let transformed_point = transform.transform_point(point);
+        let matrix_point = transform.to_matrix().mul(Vector::from_array([1.0, 1.0, 1.0, 1.0]));
+
+        // Assert
+        assert_vectors_equal(transformed_point, Vector3::new(-2.0, 4.0, 7.0));
+        assert_vectors_equal(Vector3::new(matrix_point[[0, 0]], matrix_point[[1, 0]], matrix_point[[2, 0]]), transformed_point);
+    }
+
+    #[test]
+    fn test_compose_and_inverse() {
+        // Arrange
+        let parent = example_transform();
+        let child = Transform::new(
+            Vector3::new(-1.0, 0.5, 0.0),
+            Quaternion::from_axis_angle(Vector3::new(1.0, 0.0, 0.0), 0.3),
+            Vector3::new(0.5, 1.0, 3.0));
+        let point = Vector3::new(0.3, -0.7, 1.1);
+
+        // Act
+        let composed = parent.compose(&child);
+        let inverse = parent.inverse().unwrap();
+
+        // Assert
+        assert_vectors_equal(composed.transform_point(point), parent.transform_point(child.transform_point(point)));
+        assert_vectors_equal(inverse.transform_point(parent.transform_point(point)), point);
+        assert_vectors_equal(parent.compose(&inverse).transform_point(point), point);
+
+        let zero_scale = Transform::new(Vector3::new(0.0, 0.0, 0.0), Quaternion::identity(), Vector3::new(0.0, 0.0, 0.0));
+        assert!(zero_scale.inverse().is_none());
+    }
+
+    #[test]
+    fn test_inverse_with_non_uniform_scale() {
+        // Arrange
+        let transform = Transform::new(
+            Vector3::new(1.0, -2.0, 0.5),
+            Quaternion::from_axis_angle(Vector3::new(0.0, 0.0, 1.0), 0.7),
+            Vector3::new(2.0, 1.0, 1.0));
+        let point = Vector3::new(1.0, 1.0, 0.0);
+
+        // Act
+        let inverse = transform.inverse();
+        let affine_inverse = transform.to_affine().inverse().unwrap();
+
+        // Assert
+        // Undoing the scale after the rotation would need a shear, which a Transform cannot hold
+        assert!(inverse.is_none());
+        assert_vectors_equal(affine_inverse.transform_point(transform.transform_point(point)), point);
+    }
+
+    #[test]
+    fn test_interpolate() {
+        // Arrange
+        let start = Transform::identity();
+        let end = Transform::new(
+            Vector3::new(4.0, 0.0, 0.0),
+            Quaternion::from_axis_angle(Vector3::new(0.0, 0.0, 1.0), core::f64::consts::FRAC_PI_2),
+            Vector3::new(3.0, 3.0, 3.0));
+
+        // Act
+        let halfway = start.interpolate(&end, 0.5);
+
+        // Assert
+        let x_axis = halfway.transform_vector(Vector3::new(1.0, 0.0, 0.0));
+        assert_vectors_equal(halfway.translation, Vector3::new(2.0, 0.0, 0.0));
+        assert!( approx_eq!(f64, x_axis.length(), 2.0, epsilon = 0.0001) );
+        assert!( approx_eq!(f64, x_axis.x, x_axis.y, epsilon = 0.0001) );
+        assert_eq!(start.interpolate(&end, 1.0).scale, end.scale);
+    }
+
+    #[test]
+    fn test_propagate_transforms() {
+        // Arrange
+        let root = example_transform();
+        let child = Transform::new(Vector3::new(1.0, 0.0, 0.0), Quaternion::identity(), Vector3::new(1.0, 1.0, 1.0));
+        let local = [root, child, child];
+        let parents = [None, Some(0), Some(1)];
+        let mut world = [Transform::identity(); 3];
+        let point = Vector3::new(0.0, 1.0, 0.0);
+
+        // Act
+        propagate_transforms(&local, &parents, &mut world);
+
+        // Assert
+        assert_eq!(world[0], root);
+        assert_vectors_equal(world[2].transform_point(point), root.transform_point(child.transform_point(child.transform_point(point))));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_propagate_transforms_child_before_parent() {
+        // Arrange
+        let local = [Transform::<f64>::identity(); 2];
+        let mut world = [Transform::identity(); 2];
+
+        // Act
+        propagate_transforms(&local, &[Some(1), None], &mut world);
+    }
+}
@@ -0,0 +1,214 @@
+use crate::vector2::Vector2;
+use crate::float_trait::Float;
+use crate::matrix::Matrix;
+use crate::rotation2::Rotation2;
+use crate::interpolation::Lerp;
+
+// The 2D counterpart of Transform: a scale, followed by a rotation, followed by a translation, such as the placement of a sprite.
+// It converts to a 3x3 matrix for 2D homogeneous coordinates, and to a 4x4 matrix that leaves z alone for drawing with the 3D pipeline.
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Transform2<T> where T: Float {
+    pub translation: Vector2<T>,
+    pub rotation: Rotation2<T>,
+    pub scale: Vector2<T>
+}
+
+impl<T: Float> Transform2<T> {
+    pub fn new(translation: Vector2<T>, rotation: Rotation2<T>, scale: Vector2<T>) -> Transform2<T> {
+        Transform2 {
+            translation,
+            rotation,
+            scale
+        }
+    }
+
+    pub fn identity() -> Transform2<T> {
+        Transform2::new(Vector2::new(T::zero(), T::zero()), Rotation2::identity(), Vector2::new(T::one(), T::one()))
+    }
+
+    // Transforms a point, which is affected by translation
+    pub fn transform_point(&self, point: Vector2<T>) -> Vector2<T> {
+        self.transform_vector(point) + self.translation
+    }
+
+    // Transforms a direction, which is NOT affected by translation
+    pub fn transform_vector(&self, vector: Vector2<T>) -> Vector2<T> {
+        self.rotation.rotate_vector(Vector2::new(self.scale.x * vector.x, self.scale.y * vector.y))
+    }
+
+    // Places "child", given relative to this transform, into the space this transform is relative to.
+    // Like Transform::compose, the result is only exact when the scale of this transform is uniform, since otherwise the child is sheared.
+    pub fn compose(&self, child: &Transform2<T>) -> Transform2<T> {
+        Transform2::new(
+            self.transform_point(child.translation),
+            (self.rotation * child.rotation).normalize(),
+            Vector2::new(self.scale.x * child.scale.x, self.scale.y * child.scale.y))
+    }
+
+    // The transform that undoes this one. Like Transform::inverse, returns None unless the scale is the same along both axes and not zero,
+    // since otherwise the inverse cannot be written as a Transform2. to_matrix3().inverse() gives the exact inverse in that case.
+    pub fn inverse(&self) -> Option<Transform2<T>> {
+        let is_uniform = self.scale.x == self.scale.y;
+        if !is_uniform || self.scale.x == T::zero() {
+            return None;
+        }
+
+        let rotation = self.rotation.inverse();
+        let scale = T::one() / self.scale.x;
+        let translation = rotation.rotate_vector(self.translation).scalar_multiplication(scale);
+
+        Some(Transform2::new(Vector2::new(T::zero(), T::zero()) - translation, rotation, Vector2::new(scale, scale)))
+    }
+
+    // Translation and scale are interpolated linearly, and rotation the shorter way around the circle
+    pub fn interpolate(&self, other: &Transform2<T>, t: T) -> Transform2<T> {
+        Transform2::new(
+            self.translation.lerp(other.translation, t),
+            self.rotation.slerp(other.rotation, t),
+            self.scale.lerp(other.scale, t))
+    }
+
+    // The matrix for 2D points written as (x, y, 1)
+    pub fn to_matrix3(&self) -> Matrix<T, 3, 3> {
+        Matrix::from(*self)
+    }
+
+    // The same matrix as Matrix4x4::identity().translate(..).rotate(0.0, 0.0, ..).scale(.., .., 1.0)
+    pub fn to_matrix4(&self) -> Matrix<T, 4, 4> {
+        Matrix::from(*self)
+    }
+
+    // The 2x2 part that rotates and scales
+    fn linear(&self) -> Matrix<T, 2, 2> {
+        let rotation = self.rotation.to_matrix();
+        let scale = [self.scale.x, self.scale.y];
+
+        Matrix::from_fn(|row, column| rotation[[row, column]] * scale[column])
+    }
+}
+
+// Works for Matrix3x3, which is a Matrix<f32, 3, 3>
+impl<T: Float> From<Transform2<T>> for Matrix<T, 3, 3> {
+    fn from(transform: Transform2<T>) -> Matrix<T, 3, 3> {
+        let linear = transform.linear();
+        let translation = [transform.translation.x, transform.translation.y];
+
+        Matrix::from_fn(|row, column| {
+            match (row, column) {
+                (2, 2) => T::one(),
+                (2, _) => T::zero(),
+                (_, 2) => translation[row],
+                _ => linear[[row, column]]
+            }
+        })
+    }
+}
+
+// Works for Matrix4x4, which is a Matrix<f32, 4, 4>
+impl<T: Float> From<Transform2<T>> for Matrix<T, 4, 4> {
+    fn from(transform: Transform2<T>) -> Matrix<T, 4, 4> {
+        let linear = transform.linear();
+        let translation = [transform.translation.x, transform.translation.y];
+
+        Matrix::from_fn(|row, column| {
+            match (row, column) {
+                (0..=1, 0..=1) => linear[[row, column]],
+                (0..=1, 3) => translation[row],
+                _ if row == column => T::one(),
+                _ => T::zero()
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vector3::Vector3;
+    use crate::matrix3x3::Matrix3x3;
+    use crate::matrix4x4::Matrix4x4;
+    use float_cmp::approx_eq;
+
+    fn assert_vectors_equal(a: Vector2<f32>, b: Vector2<f32>) {
+        assert!( approx_eq!(f32, (a - b).length(), 0.0, epsilon = 0.0001) );
+    }
+
+    fn example_transform() -> Transform2<f32> {
+        Transform2::new(Vector2::new(4.0, -2.0), Rotation2::new(0.7), Vector2::new(2.0, 3.0))
+    }
+
+    #[test]
+    fn test_matches_matrix4x4() {
+        // Arrange
+        let transform = example_transform();
+        let expected = Matrix4x4::identity().translate(Vector2::new(4.0, -2.0)).rotate(0.0, 0.0, 0.7).scale(2.0, 3.0, 1.0);
+        let point = Vector2::new(1.0, 5.0);
+
+        // Act
+        let matrix4 = Matrix4x4::from(transform);
+        let matrix3 = Matrix3x3::from(transform);
+
+        // Assert
+        for row in 0..4 {
+            for column in 0..4 {
+                assert!( approx_eq!(f32, matrix4[[row, column]], expected[[row, column]], epsilon = 0.0001) );
+            }
+        }
+
+        let point3 = matrix3.mul_vector3(Vector3::new(point.x, point.y, 1.0));
+        assert_vectors_equal(Vector2::new(point3.x, point3.y), transform.transform_point(point));
+        assert_eq!(point3.z, 1.0);
+    }
+
+    #[test]
+    fn test_compose_and_inverse() {
+        // Arrange
+        let parent = Transform2::new(Vector2::new(1.0, 1.0), Rotation2::new(-1.2), Vector2::new(2.0, 2.0));
+        let child = example_transform();
+        let point = Vector2::new(0.5, -0.25);
+
+        // Act
+        let composed = parent.compose(&child);
+        let inverse = parent.inverse().unwrap();
+
+        // Assert
+        assert_vectors_equal(composed.transform_point(point), parent.transform_point(child.transform_point(point)));
+        assert_vectors_equal(inverse.transform_point(parent.transform_point(point)), point);
+        assert!(Transform2::new(Vector2::new(0.0, 0.0), Rotation2::identity(), Vector2::new(0.0, 0.0)).inverse().is_none());
+    }
+
+    #[test]
+    fn test_inverse_with_non_uniform_scale() {
+        // Arrange
+        let transform = example_transform();
+        let point = Vector2::new(1.0, 1.0);
+        let transformed_point = transform.transform_point(point);
+
+        // Act
+        let inverse = transform.inverse();
+        let matrix_inverse = transform.to_matrix3().inverse().unwrap();
+
+        // Assert
+        assert!(inverse.is_none());
+
+        let round_trip = matrix_inverse.mul_vector3(Vector3::new(transformed_point.x, transformed_point.y, 1.0));
+        assert_vectors_equal(Vector2::new(round_trip.x, round_trip.y), point);
+    }
+
+    #[test]
+    fn test_interpolate() {
+        // Arrange
+        let start = Transform2::identity();
+        let end = Transform2::new(Vector2::new(2.0, 4.0), Rotation2::new(core::f32::consts::FRAC_PI_2), Vector2::new(3.0, 3.0));
+
+        // Act
+        let halfway = start.interpolate(&end, 0.5);
+
+        // Assert
+        assert_vectors_equal(halfway.translation, Vector2::new(1.0, 2.0));
+        assert!( approx_eq!(f32, halfway.rotation.angle(), core::f32::consts::FRAC_PI_4, epsilon = 0.0001) );
+        assert_vectors_equal(halfway.scale, Vector2::new(2.0, 2.0));
+    }
+}
@@ -0,0 +1,451 @@
+use alloc::vec::Vec;
+
+use crate::vector2::Vector2;
+use crate::vector3::Vector3;
+use crate::vector4::Vector4;
+use crate::matrix3x3::Matrix3x3;
+use crate::matrix4x4::Matrix4x4;
+
+// The memory layout rules that GLSL uses for interface blocks, see section 7.6.2.2 of the OpenGL 4.6 specification.
+// Std140 can be used for uniform blocks, while Std430 can only be used for shader storage blocks (and push constants in Vulkan).
+// The difference is that Std140 rounds the alignment of arrays and structs up to that of a vec4 (16 bytes), while Std430 does not.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum LayoutRules {
+    Std140,
+    Std430
+}
+
+// Rounds "offset" up to the nearest multiple of "alignment"
+pub fn align_to(offset: usize, alignment: usize) -> usize {
+    offset.div_ceil(alignment) * alignment
+}
+
+// A type that can be written into a buffer following the GLSL layout rules.
+// Implement it for your own structs with the "uniform_block!" macro.
+pub trait UniformLayout {
+    // The base alignment of the type. The offset of a member of this type is rounded up to a multiple of it.
+    fn alignment(rules: LayoutRules) -> usize;
+
+    // The number of bytes the type occupies, including any padding at the end that the following member has to skip over.
+    // For vec3, this is 12, since a float may directly follow it.
+    fn size(rules: LayoutRules) -> usize;
+
+    // Writes the value at the current position of the writer, which is already aligned to the alignment of the type.
+    fn write(&self, writer: &mut LayoutWriter);
+}
+
+// Collects the bytes of the values written into it, adding the padding required by the layout rules in between.
+// The bytes are in the native byte order, which is what the GPU expects when uploading from the same machine.
+pub struct LayoutWriter {
+    rules: LayoutRules,
+    bytes: Vec<u8>
+}
+
+impl LayoutWriter {
+    pub fn new(rules: LayoutRules) -> LayoutWriter {
+        LayoutWriter {
+            rules,
+            bytes: Vec::new()
+        }
+    }
+
+    pub fn rules(&self) -> LayoutRules {
+        self.rules
+    }
+
+    // The offset that the next value will be written at, before alignment
+    pub fn offset(&self) -> usize {
+        self.bytes.len()
+    }
+
+    // Aligns the writer to the type of the value and writes it.
+    // Returns the offset the value was written at.
+    pub fn write<T: UniformLayout>(&mut self, value: &T) -> usize {
+        self.align(T::alignment(self.rules));
+
+        let offset = self.offset();
+        value.write(self);
+
+        offset
+    }
+
+    // Pads with zeroes until the offset is a multiple of "alignment"
+    pub fn align(&mut self, alignment: usize) {
+        let aligned_offset = align_to(self.offset(), alignment);
+
+        self.bytes.resize(aligned_offset, 0);
+    }
+
+    pub fn write_bytes(&mut self, bytes: &[u8]) {
+        self.bytes.extend_from_slice(bytes);
+    }
+
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+}
+
+// Writes a single value with the given layout rules, padded to the full size of the type.
+pub fn to_bytes<T: UniformLayout>(value: &T, rules: LayoutRules) -> Vec<u8> {
+    let mut writer = LayoutWriter::new(rules);
+
+    writer.write(value);
+    writer.align(T::alignment(rules));
+
+    writer.into_bytes()
+}
+
+macro_rules! scalar_layout {
+    ($($scalar:ty),+) => {
+        $(
+            impl UniformLayout for $scalar {
+                fn alignment(_rules: LayoutRules) -> usize {
+                    core::mem::size_of::<$scalar>()
+                }
+
+                fn size(_rules: LayoutRules) -> usize {
+                    core::mem::size_of::<$scalar>()
+                }
+
+                fn write(&self, writer: &mut LayoutWriter) {
+                    writer.write_bytes(&self.to_ne_bytes());
+                }
+            }
+        )+
+    };
+}
+
+scalar_layout!(f32, f64, i32, u32);
+
+// A vector with two components has twice the alignment of its scalar, and vectors with three or four components have four times the alignment.
+// The components are tightly packed.
+macro_rules! vector_layout {
+    ($vector:ident, $length:expr, $alignment_factor:expr, $($component:ident),+) => {
+        impl<T> UniformLayout for $vector<T> where T: UniformLayout + core::ops::Add<Output = T> + core::ops::Sub<Output = T> + core::ops::Mul<Output = T> + Copy {
+            fn alignment(rules: LayoutRules) -> usize {
+                T::alignment(rules) * $alignment_factor
+            }
+
+            fn size(rules: LayoutRules) -> usize {
+                T::size(rules) * $length
+            }
+
+            fn write(&self, writer: &mut LayoutWriter) {
+                $( self.$component.write(writer); )+
+            }
+        }
+    };
+}
+
+vector_layout!(Vector2, 2, 2, x, y);
+vector_layout!(Vector3, 3, 4, x, y, z);
+vector_layout!(Vector4, 4, 4, x, y, z, w);
+
+// Arrays are laid out with a fixed stride between elements, which is the element size rounded up to its alignment.
+// Std140 additionally rounds the alignment, and with it the stride, up to 16 bytes, so that a float[4] takes up 64 bytes.
+impl<T: UniformLayout, const N: usize> UniformLayout for [T; N] {
+    fn alignment(rules: LayoutRules) -> usize {
+        match rules {
+            LayoutRules::Std140 => align_to(T::alignment(rules), 16),
+            LayoutRules::Std430 => T::alignment(rules)
+        }
+    }
+
+    fn size(rules: LayoutRules) -> usize {
+        array_stride::<T>(rules) * N
+    }
+
+    fn write(&self, writer: &mut LayoutWriter) {
+        let start = writer.offset();
+        let stride = array_stride::<T>(writer.rules());
+
+        for (index, element) in self.iter().enumerate() {
+            writer.write(element);
+            writer.bytes.resize(start + (index + 1) * stride, 0);
+        }
+    }
+}
+
+fn array_stride<T: UniformLayout>(rules: LayoutRules) -> usize {
+    align_to(T::size(rules), <[T; 1]>::alignment(rules))
+}
+
+// A column-major matrix is laid out the same as an array of its column vectors.
+// Note that this means a mat3 takes up 48 bytes with both rules, since every vec3 column is aligned to 16 bytes.
+impl UniformLayout for Matrix3x3 {
+    fn alignment(rules: LayoutRules) -> usize {
+        <[Vector3<f32>; 3]>::alignment(rules)
+    }
+
+    fn size(rules: LayoutRules) -> usize {
+        <[Vector3<f32>; 3]>::size(rules)
+    }
+
+    fn write(&self, writer: &mut LayoutWriter) {
+        let m = self;
+
+        [Vector3::new(m[[0, 0]], m[[1, 0]], m[[2, 0]]),
+         Vector3::new(m[[0, 1]], m[[1, 1]], m[[2, 1]]),
+         Vector3::new(m[[0, 2]], m[[1, 2]], m[[2, 2]])].write(writer);
+    }
+}
+
+impl UniformLayout for Matrix4x4 {
+    fn alignment(rules: LayoutRules) -> usize {
+        <[Vector4<f32>; 4]>::alignment(rules)
+    }
+
+    fn size(rules: LayoutRules) -> usize {
+        <[Vector4<f32>; 4]>::size(rules)
+    }
+
+    fn write(&self, writer: &mut LayoutWriter) {
+        for column in 0..4 {
+            for row in 0..4 {
+                self[[row, column]].write(writer);
+            }
+        }
+    }
+}
+
+// Defines a struct and implements UniformLayout for it, so that it can be used as a uniform block or nested inside of one.
+// The members are written in the order they are declared. A struct is aligned to its most aligned member,
+// which Std140 rounds up to 16 bytes, and its size is padded to a multiple of its alignment.
+//
+// uniform_block! {
+//     pub struct Light {
+//         pub position: Vector3<f32>,
+//         pub intensity: f32
+//     }
+// }
+#[macro_export]
+macro_rules! uniform_block {
+    ($(#[$meta:meta])* $visibility:vis struct $name:ident { $($member_visibility:vis $member:ident: $member_type:ty),* $(,)? }) => {
+        $(#[$meta])*
+        $visibility struct $name {
+            $($member_visibility $member: $member_type),*
+        }
+
+        impl $crate::uniform_layout::UniformLayout for $name {
+            fn alignment(rules: $crate::uniform_layout::LayoutRules) -> usize {
+                let mut alignment = match rules {
+                    $crate::uniform_layout::LayoutRules::Std140 => 16,
+                    $crate::uniform_layout::LayoutRules::Std430 => 1
+                };
+
+                $(
+                    alignment = alignment.max(<$member_type as $crate::uniform_layout::UniformLayout>::alignment(rules));
+                )*
+
+                alignment
+            }
+
+            fn size(rules: $crate::uniform_layout::LayoutRules) -> usize {
+                let mut offset = 0;
+
+                $(
+                    offset = $crate::uniform_layout::align_to(offset, <$member_type as $crate::uniform_layout::UniformLayout>::alignment(rules));
+                    offset += <$member_type as $crate::uniform_layout::UniformLayout>::size(rules);
+                )*
+
+                $crate::uniform_layout::align_to(offset, <Self as $crate::uniform_layout::UniformLayout>::alignment(rules))
+            }
+
+            fn write(&self, writer: &mut $crate::uniform_layout::LayoutWriter) {
+                $(
+                    writer.write(&self.$member);
+                )*
+
+                writer.align(<Self as $crate::uniform_layout::UniformLayout>::alignment(writer.rules()));
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read_f32(bytes: &[u8], offset: usize) -> f32 {
+        f32::from_ne_bytes([bytes[offset], bytes[offset + 1], bytes[offset + 2], bytes[offset + 3]])
+    }
+
+    fn read_u32(bytes: &[u8], offset: usize) -> u32 {
+        u32::from_ne_bytes([bytes[offset], bytes[offset + 1], bytes[offset + 2], bytes[offset + 3]])
+    }
+
+    #[test]
+    fn test_vector_alignment() {
+        // Act & Assert
+        assert_eq!(Vector2::<f32>::alignment(LayoutRules::Std140), 8);
+        assert_eq!(Vector3::<f32>::alignment(LayoutRules::Std140), 16);
+        assert_eq!(Vector3::<f32>::size(LayoutRules::Std140), 12);
+        assert_eq!(Vector4::<f32>::alignment(LayoutRules::Std430), 16);
+        assert_eq!(Vector3::<f64>::alignment(LayoutRules::Std430), 32);
+    }
+
+    #[test]
+    fn test_array_stride() {
+        // Act & Assert
+        assert_eq!(<[f32; 4]>::size(LayoutRules::Std140), 64);
+        assert_eq!(<[f32; 4]>::size(LayoutRules::Std430), 16);
+        assert_eq!(<[Vector3<f32>; 2]>::size(LayoutRules::Std430), 32);
+        assert_eq!(<[Vector2<f32>; 2]>::size(LayoutRules::Std140), 32);
+        assert_eq!(<[Vector2<f32>; 2]>::size(LayoutRules::Std430), 16);
+    }
+
+    #[test]
+    fn test_matrix_layout() {
+        // Arrange
+        let matrix = Matrix3x3::new(
+            1.0, 2.0, 3.0,
+            4.0, 5.0, 6.0,
+            7.0, 8.0, 9.0);
+
+        // Act
+        let bytes = to_bytes(&matrix, LayoutRules::Std430);
+
+        // Assert
+        // Each column is padded to 16 bytes
+        assert_eq!(bytes.len(), 48);
+        assert_eq!(read_f32(&bytes, 0), 1.0);
+        assert_eq!(read_f32(&bytes, 4), 4.0);
+        assert_eq!(read_f32(&bytes, 16), 2.0);
+        assert_eq!(read_f32(&bytes, 32), 3.0);
+        assert_eq!(Matrix4x4::size(LayoutRules::Std140), 64);
+    }
+
+    uniform_block! {
+        struct Light {
+            position: Vector3<f32>,
+            intensity: f32
+        }
+    }
+
+    #[test]
+    fn test_vec3_followed_by_float() {
+        // Arrange
+        let light = Light { position: Vector3::new(1.0, 2.0, 3.0), intensity: 0.5 };
+
+        // Act
+        let bytes = to_bytes(&light, LayoutRules::Std140);
+
+        // Assert
+        // The float fills the gap after the vec3
+        assert_eq!(bytes.len(), 16);
+        assert_eq!(read_f32(&bytes, 12), 0.5);
+    }
+
+    // The example from section 7.6.2.2 of the OpenGL 4.6 specification, leaving out the mat2x3 member, and with a uvec2 in place of the bvec2.
+    //
+    // layout(std140) uniform Example {
+    //     float a;
+    //     vec2 b;
+    //     vec3 c;
+    //     struct { int d; bvec2 e; } f;
+    //     float g;
+    //     float h[2];
+    //     struct { uvec3 j; vec2 k; float l[2]; vec2 m; mat3 n[2]; } o[2];
+    // };
+    uniform_block! {
+        struct F {
+            d: i32,
+            e: Vector2<u32>
+        }
+    }
+
+    uniform_block! {
+        struct O {
+            j: Vector3<u32>,
+            k: Vector2<f32>,
+            l: [f32; 2],
+            m: Vector2<f32>,
+            n: [Matrix3x3; 2]
+        }
+    }
+
+    uniform_block! {
+        struct Example {
+            a: f32,
+            b: Vector2<f32>,
+            c: Vector3<f32>,
+            f: F,
+            g: f32,
+            h: [f32; 2],
+            o: [O; 2]
+        }
+    }
+
+    fn example() -> Example {
+        let o = |first: u32| O {
+            j: Vector3::new(first, first + 1, first + 2),
+            k: Vector2::new(10.0, 11.0),
+            l: [12.0, 13.0],
+            m: Vector2::new(14.0, 15.0),
+            n: [Matrix3x3::identity(), Matrix3x3::identity()]
+        };
+
+        Example {
+            a: 1.0,
+            b: Vector2::new(2.0, 3.0),
+            c: Vector3::new(4.0, 5.0, 6.0),
+            f: F { d: 7, e: Vector2::new(1, 0) },
+            g: 8.0,
+            h: [9.0, 9.5],
+            o: [o(100), o(200)]
+        }
+    }
+
+    #[test]
+    fn test_std140_reference_offsets() {
+        // Act
+        let bytes = to_bytes(&example(), LayoutRules::Std140);
+
+        // Assert
+        assert_eq!(read_f32(&bytes, 0), 1.0);     // a
+        assert_eq!(read_f32(&bytes, 8), 2.0);     // b
+        assert_eq!(read_f32(&bytes, 16), 4.0);    // c
+        assert_eq!(read_u32(&bytes, 32), 7);      // f.d
+        assert_eq!(read_u32(&bytes, 40), 1);      // f.e
+        assert_eq!(read_f32(&bytes, 48), 8.0);    // g
+        assert_eq!(read_f32(&bytes, 64), 9.0);    // h[0]
+        assert_eq!(read_f32(&bytes, 80), 9.5);    // h[1]
+        assert_eq!(read_u32(&bytes, 96), 100);    // o[0].j
+        assert_eq!(read_f32(&bytes, 112), 10.0);  // o[0].k
+        assert_eq!(read_f32(&bytes, 128), 12.0);  // o[0].l[0]
+        assert_eq!(read_f32(&bytes, 144), 13.0);  // o[0].l[1]
+        assert_eq!(read_f32(&bytes, 160), 14.0);  // o[0].m
+        assert_eq!(read_f32(&bytes, 176), 1.0);   // o[0].n[0], first column
+        assert_eq!(read_f32(&bytes, 196), 1.0);   // o[0].n[0], second column
+        assert_eq!(read_f32(&bytes, 224), 1.0);   // o[0].n[1]
+        assert_eq!(read_u32(&bytes, 272), 200);   // o[1].j
+        assert_eq!(bytes.len(), 448);
+        assert_eq!(Example::size(LayoutRules::Std140), 448);
+    }
+
+    #[test]
+    fn test_std430_reference_offsets() {
+        // Act
+        let bytes = to_bytes(&example(), LayoutRules::Std430);
+
+        // Assert
+        // Up to "h" nothing changes, except that "f" is only aligned to 8 bytes and is 16 bytes in size
+        assert_eq!(read_u32(&bytes, 32), 7);      // f.d
+        assert_eq!(read_f32(&bytes, 48), 8.0);    // g
+        assert_eq!(read_f32(&bytes, 52), 9.0);    // h[0]
+        assert_eq!(read_f32(&bytes, 56), 9.5);    // h[1]
+        assert_eq!(read_u32(&bytes, 64), 100);    // o[0].j
+        assert_eq!(read_f32(&bytes, 80), 10.0);   // o[0].k
+        assert_eq!(read_f32(&bytes, 88), 12.0);   // o[0].l[0]
+        assert_eq!(read_f32(&bytes, 92), 13.0);   // o[0].l[1]
+        assert_eq!(read_f32(&bytes, 96), 14.0);   // o[0].m
+        assert_eq!(read_f32(&bytes, 112), 1.0);   // o[0].n[0]
+        assert_eq!(read_f32(&bytes, 160), 1.0);   // o[0].n[1]
+        assert_eq!(read_u32(&bytes, 208), 200);   // o[1].j
+        assert_eq!(bytes.len(), 352);
+    }
+}
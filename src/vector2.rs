@@ -4,71 +4,94 @@
 // TODO: Can you do parameterized tests in Rust???
 // TODO: Write proper documentation for the library with Rust documentation Markdown
 
-// std::ops contains overloadable operators.
+// core::ops (re-exported as std::ops) contains overloadable operators.
 // In Rust, operator overloading happens by implementing Traits that back up the methods for different operators.
 // For example, the "+" operator is backed by the .Add method of the Add trait.
 // Notice that this also means that operator overloading can only happen on operators backed by traits.
 // It's also not possible to create new operators.
-use std::ops::{Add, Sub, Mul};
+use core::ops::{Add, Sub, Mul, Neg, Deref, DerefMut};
 
-use crate::sqrt_trait::Sqrt;
+use crate::matrix::Vector;
+use crate::float_trait::Float;
 
-// By default, variable bindings have "move semantics".
-// For our Vectors, this means that if we assign one to a variable "a" and then afterwards assign
-// It to a variable "b", it will have "moved out of a and into b", meaning variable a can no longer be used.
-// I would like Vectors to have "copy semantics", meaning that the values of the vector can simply be copied at bit level.
-// NOTICE: Using the derive strategy for making a type copyable means that a trait bound is put on "T" for Copy and Clone.
-#[derive(Copy, Clone)]
-pub struct Vector2<T> where T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy {
+// A Vector2 is the generic Vector with 2 components, which is a Matrix with 2 rows and 1 column.
+// This means that the operators, dot_product, length and the component-wise functions are written once in matrix.rs and work for every size,
+// and that a Vector2 can be multiplied by a Matrix without converting it first. Only what is specific to 2 components is written here.
+pub type Vector2<T> = Vector<T, 2>;
+
+// The components of a Vector2 by name. Vector2 dereferences to this, so the components are read and written as "vector.x" and "vector.y".
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+#[repr(C)]
+pub struct XY<T> {
     pub x: T,
     pub y: T
 }
 
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy> Deref for Vector2<T> {
+    type Target = XY<T>;
+
+    fn deref(&self) -> &XY<T> {
+        // SAFETY: Both the vector and XY are "repr(C)" and consist of exactly 2 T's without padding, so they have the same layout.
+        unsafe { &*(self as *const Vector2<T> as *const XY<T>) }
+    }
+}
+
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy> DerefMut for Vector2<T> {
+    fn deref_mut(&mut self) -> &mut XY<T> {
+        // SAFETY: See deref
+        unsafe { &mut *(self as *mut Vector2<T> as *mut XY<T>) }
+    }
+}
+
 impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy> Vector2<T> {
     pub fn new(x: T, y: T) -> Vector2<T> {
-        Vector2::<T> {
-            x,
-            y
-        }
+        Vector::from_array([x, y])
     }
 
-    pub fn scalar_multiplication(self, scalar: T) -> Vector2<T> {
-        Vector2 {
-            x: self.x * scalar,
-            y: self.y * scalar
-        }
+    // The z component of the cross product of the two vectors extended with z = 0, also called the 2D cross product.
+    // It is positive when "other" is counterclockwise from this vector, negative when clockwise, and zero when they are parallel.
+    pub fn perp_dot(self, other: Self) -> T {
+        self.x * other.y - self.y * other.x
     }
+}
 
-    pub fn dot_product(self, other: Self) -> T {
-        self.x * other.x + self.y * other.y
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy + Neg<Output = T>> Vector2<T> {
+    // The vector rotated 90 degrees counterclockwise, such as the normal of an edge
+    pub fn perp(self) -> Vector2<T> {
+        Vector2::new(-self.y, self.x)
     }
 }
 
-impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Sqrt + Copy> Vector2<T> {
-    pub fn length(self) -> T {
-        (self.x * self.x + self.y * self.y).sqrt()
+impl<T: Float> Vector2<T> {
+    // The unit vector pointing in the direction of "angle", in radians counterclockwise from the positive x axis
+    pub fn from_angle(angle: T) -> Self {
+        Vector2::new(angle.cos(), angle.sin())
     }
-}
 
-impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy> Add for Vector2<T> {
-    type Output = Self;
+    // The angle of the vector in radians counterclockwise from the positive x axis, between -pi and pi
+    pub fn angle(self) -> T {
+        self.y.atan2(self.x)
+    }
+
+    // Rotates the vector counterclockwise by "angle" radians. Use a Rotation2 when rotating many vectors by the same angle.
+    pub fn rotate(self, angle: T) -> Self {
+        let (sin, cos) = (angle.sin(), angle.cos());
 
-    fn add(self, other: Self) -> Self {
-        Self {
-            x: self.x + other.x,
-            y: self.y + other.y
-        }
+        Vector2::new(self.x * cos - self.y * sin, self.x * sin + self.y * cos)
     }
 }
 
-impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy> Sub for Vector2<T> {
-    type Output = Self;
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy> From<(T, T)> for Vector2<T> {
+    fn from(tuple: (T, T)) -> Vector2<T> {
+        let (x, y) = tuple;
+
+        Vector2::new(x, y)
+    }
+}
 
-    fn sub(self, other: Self) -> Self {
-        Self {
-            x: self.x - other.x,
-            y: self.y - other.y
-        }
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy> From<Vector2<T>> for (T, T) {
+    fn from(vector: Vector2<T>) -> (T, T) {
+        (vector.x, vector.y)
     }
 }
 
@@ -120,6 +143,41 @@ mod tests {
         assert_eq!(dot_product_result, 75.0);
     }
 
+    #[test]
+    fn test_perp_and_perp_dot() {
+        // Arrange
+        let vector_a = Vector2::new(3, 7);
+        let vector_b = Vector2::new(4, 9);
+
+        // Act
+        let perp = vector_a.perp();
+        let perp_dot = vector_a.perp_dot(vector_b);
+
+        // Assert
+        assert_eq!(perp, Vector2::new(-7, 3));
+        assert_eq!(perp.dot_product(vector_a), 0);
+        assert_eq!(perp_dot, -1);
+        assert_eq!(vector_b.perp_dot(vector_a), 1);
+    }
+
+    #[test]
+    fn test_angle_and_rotate() {
+        // Arrange
+        let vector = Vector2::new(2.0, 0.0);
+
+        // Act
+        let rotated = vector.rotate(core::f64::consts::FRAC_PI_2);
+        let from_angle = Vector2::<f64>::from_angle(3.0);
+
+        // Assert
+        assert!( approx_eq!(f64, rotated.x, 0.0, epsilon = 0.0001) );
+        assert!( approx_eq!(f64, rotated.y, 2.0, epsilon = 0.0001) );
+        assert!( approx_eq!(f64, rotated.angle(), core::f64::consts::FRAC_PI_2, epsilon = 0.0001) );
+        assert!( approx_eq!(f64, from_angle.angle(), 3.0, epsilon = 0.0001) );
+        assert!( approx_eq!(f64, from_angle.length(), 1.0, epsilon = 0.0001) );
+        assert!( approx_eq!(f64, Vector2::new(-1.0, -1.0).angle(), -3.0 * core::f64::consts::FRAC_PI_4, epsilon = 0.0001) );
+    }
+
     #[test]
     fn test_scalar_multiplication() {
         // Arrange
@@ -160,4 +218,262 @@ mod tests {
         assert_eq!(subtraction_result.x, 1.0);
         assert_eq!(subtraction_result.y, 0.0);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_min_max_clamp() {
+        // Arrange
+        let vector_a = Vector2::<f32>::new(1.0, 5.0);
+        let vector_b = Vector2::<f32>::new(2.0, 4.0);
+
+        // Act
+        let min_result = vector_a.min(vector_b);
+        let max_result = vector_a.max(vector_b);
+        let clamp_result = vector_a.clamp(Vector2::new(0.0, 0.0), Vector2::new(1.0, 1.0));
+
+        // Assert
+        assert_eq!(min_result.x, 1.0);
+        assert_eq!(min_result.y, 4.0);
+        assert_eq!(max_result.x, 2.0);
+        assert_eq!(max_result.y, 5.0);
+        assert_eq!(clamp_result.x, 1.0);
+        assert_eq!(clamp_result.y, 1.0);
+    }
+
+    #[test]
+    fn test_rounding_functions() {
+        // Arrange
+        let vector_a = Vector2::<f32>::new(1.5, -2.5);
+
+        // Act
+        let abs_result = vector_a.abs();
+        let floor_result = vector_a.floor();
+        let ceil_result = vector_a.ceil();
+        let round_result = vector_a.round();
+        let fract_result = vector_a.fract();
+
+        // Assert
+        assert_eq!(abs_result.y, 2.5);
+        assert_eq!(floor_result.x, 1.0);
+        assert_eq!(floor_result.y, -3.0);
+        assert_eq!(ceil_result.x, 2.0);
+        assert_eq!(ceil_result.y, -2.0);
+        assert_eq!(round_result.x, 2.0);
+        assert_eq!(round_result.y, -3.0);
+        assert_eq!(fract_result.x, 0.5);
+        assert_eq!(fract_result.y, 0.5);
+    }
+
+    #[test]
+    fn test_integer_vector_as_hash_map_key() {
+        // Arrange
+        let mut tiles = std::collections::HashMap::new();
+        tiles.insert(Vector2::new(1, 2), "grass");
+
+        // Act
+        let tile = tiles.get(&Vector2::new(1, 2));
+
+        // Assert
+        assert_eq!(tile, Some(&"grass"));
+    }
+
+    #[test]
+    fn test_ordering() {
+        // Arrange
+        let vector_a = Vector2::new(1, 5);
+        let vector_b = Vector2::new(2, 0);
+
+        // Act & Assert
+        assert!(vector_a < vector_b);
+        assert_eq!(Ord::max(vector_a, vector_b), vector_b);
+    }
+
+    #[test]
+    fn test_manhattan_and_chebyshev_length() {
+        // Arrange
+        let vector_a = Vector2::<i32>::new(-3, 2);
+
+        // Act
+        let manhattan_length = vector_a.manhattan_length();
+        let chebyshev_length = vector_a.chebyshev_length();
+
+        // Assert
+        assert_eq!(manhattan_length, 5);
+        assert_eq!(chebyshev_length, 3);
+    }
+
+    #[test]
+    fn test_checked_lengths_at_integer_limits() {
+        // Arrange
+        // The absolute value of i32::MIN is one more than i32::MAX
+        let smallest = Vector2::<i32>::new(i32::MIN, 0);
+        let largest = Vector2::<i32>::new(i32::MIN + 1, 0);
+
+        // Act & Assert
+        assert_eq!(smallest.checked_manhattan_length(), None);
+        assert_eq!(smallest.checked_chebyshev_length(), None);
+        assert_eq!(largest.checked_manhattan_length(), Some(i32::MAX));
+        assert_eq!(largest.checked_chebyshev_length(), Some(i32::MAX));
+        assert_eq!(Vector2::<i32>::new(i32::MAX, 1).checked_manhattan_length(), None);
+        assert_eq!(Vector2::<u8>::new(200, 55).checked_manhattan_length(), Some(255));
+    }
+
+    #[test]
+    fn test_overflow_arithmetic() {
+        // Arrange
+        let vector_a = Vector2::<u8>::new(250, 1);
+        let vector_b = Vector2::<u8>::new(10, 1);
+
+        // Act
+        let checked_result = vector_a.checked_add(vector_b);
+        let saturating_result = vector_a.saturating_add(vector_b);
+        let wrapping_result = vector_a.wrapping_add(vector_b);
+
+        // Assert
+        assert_eq!(checked_result, None);
+        assert_eq!(saturating_result.x, 255);
+        assert_eq!(wrapping_result.x, 4);
+        assert_eq!(wrapping_result.y, 2);
+    }
+
+    #[test]
+    fn test_remainder_and_shifts() {
+        // Arrange
+        let vector_a = Vector2::<i32>::new(7, -7);
+
+        // Act
+        let remainder = vector_a % Vector2::new(4, 4);
+        let shifted_left = vector_a << 1;
+        let shifted_right = vector_a >> 1;
+
+        // Assert
+        assert_eq!(remainder.x, 3);
+        assert_eq!(remainder.y, -3);
+        assert_eq!(shifted_left.x, 14);
+        assert_eq!(shifted_right.x, 3);
+    }
+
+    #[test]
+    fn test_casting() {
+        // Arrange
+        let integer_vector = Vector2::<i32>::new(1, -2);
+        let float_vector = Vector2::<f32>::new(1.5, -2.5);
+
+        // Act
+        let converted_to_float = integer_vector.to_f32();
+        let converted_to_integer = Vector2::<f32>::new(1.5, -2.5).try_to_i32();
+
+        // Assert
+        assert_eq!(converted_to_float.y, -2.0);
+        assert_eq!(converted_to_integer, Some(Vector2::new(1, -2)));
+        assert_eq!(float_vector.try_to_u32(), None);
+    }
+
+    #[test]
+    fn test_display() {
+        // Arrange
+        let vector_a = Vector2::new(1.0, -2.5);
+
+        // Act
+        let display = format!("{}", vector_a);
+        let display_with_precision = format!("{:.1}", vector_a);
+        let display_with_width = format!("{:5.2}", vector_a);
+
+        // Assert
+        assert_eq!(display, "(1, -2.5)");
+        assert_eq!(display_with_precision, "(1.0, -2.5)");
+        assert_eq!(display_with_width, "( 1.00, -2.50)");
+    }
+
+    #[test]
+    fn test_debug() {
+        // Arrange
+        let vector_a = Vector2::new(1.0, -2.5);
+
+        // Act
+        let debug = format!("{:?}", vector_a);
+        let pretty_debug = format!("{:#?}", vector_a);
+
+        // Assert
+        assert_eq!(debug, "Vector2 { x: 1.0, y: -2.5 }");
+        assert_eq!(pretty_debug.lines().count(), 4);
+    }
+
+    #[test]
+    fn test_from_str() {
+        // Act
+        let parsed_vector = "1.0, 2.0".parse::<Vector2<f32>>().unwrap();
+
+        // Assert
+        assert_eq!(parsed_vector, Vector2::new(1.0, 2.0));
+    }
+
+    #[test]
+    fn test_display_round_trip() {
+        // Arrange
+        let vector_a = Vector2::<f32>::new(0.1, -2.5);
+
+        // Act
+        let parsed_vector: Vector2<f32> = vector_a.to_string().parse().unwrap();
+
+        // Assert
+        assert_eq!(parsed_vector, vector_a);
+    }
+
+    #[test]
+    fn test_from_str_error() {
+        // Act
+        let error = "1.0, oops".parse::<Vector2<f32>>().unwrap_err();
+
+        // Assert
+        assert_eq!(error.span(), 5..9);
+    }
+
+    #[test]
+    fn test_memory_layout() {
+        // Act & Assert
+        assert_eq!(std::mem::size_of::<Vector2<f32>>(), 8);
+        assert_eq!(std::mem::align_of::<Vector2<f32>>(), 4);
+        assert_eq!(std::mem::size_of::<Vector2<f64>>(), 16);
+        assert_eq!(std::mem::size_of::<Vector2<u8>>(), 2);
+        assert_eq!(std::mem::offset_of!(XY<f32>, x), 0);
+        assert_eq!(std::mem::offset_of!(XY<f32>, y), 4);
+    }
+
+    #[test]
+    fn test_array_and_tuple_conversions() {
+        // Arrange
+        let vector_a = Vector2::<i32>::new(1, 2);
+
+        // Act
+        let array: [i32; 2] = vector_a.into();
+        let tuple: (i32, i32) = vector_a.into();
+
+        // Assert
+        assert_eq!(array, [1, 2]);
+        assert_eq!(tuple, (1, 2));
+        assert_eq!(Vector2::from(array), vector_a);
+        assert_eq!(Vector2::from(tuple), vector_a);
+    }
+
+    #[test]
+    fn test_flat_slice_views() {
+        // Arrange
+        let mut vectors = [Vector2::<f32>::new(1.0, 2.0), Vector2::<f32>::new(3.0, 4.0)];
+        let components = [1.0, 2.0, 3.0, 4.0];
+
+        // Act
+        let flat_slice = Vector2::as_flat_slice(&vectors);
+        let vector_slice = Vector2::from_flat_slice(&components).unwrap();
+
+        // Assert
+        assert_eq!(flat_slice, &components);
+        assert_eq!(vector_slice, &vectors);
+        assert!(Vector2::from_flat_slice(&components[1..]).is_none());
+
+        // Act
+        Vector2::as_flat_slice_mut(&mut vectors)[2] = -1.0;
+
+        // Assert
+        assert_eq!(vectors[1].x, -1.0);
+    }
+}
@@ -1,79 +1,66 @@
 use crate::vector2::Vector2;
-use std::ops::{Add, Sub, Mul};
+use core::ops::{Add, Sub, Mul, Deref, DerefMut};
 
-use crate::sqrt_trait::Sqrt;
+use crate::matrix::Vector;
 
-// By default, variable bindings have "move semantics".
-// For our Vectors, this means that if we assign one to a variable "a" and then afterwards assign
-// It to a variable "b", it will have "moved out of a and into b", meaning variable a can no longer be used.
-// I would like Vectors to have "copy semantics", meaning that the values of the vector can simply be copied at bit level.
-// NOTICE: Using the derive strategy for making a type copyable means that a trait bound is put on "T" for Copy and Clone.
-#[derive(Copy, Clone)]
-pub struct Vector3<T> where T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy {
+// A Vector3 is the generic Vector with 3 components, so like Vector2 it shares everything that is not specific to its size with Vector and Matrix.
+pub type Vector3<T> = Vector<T, 3>;
+
+// The components of a Vector3 by name, reached through Deref as "vector.x", "vector.y" and "vector.z"
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+#[repr(C)]
+pub struct XYZ<T> {
     pub x: T,
     pub y: T,
     pub z: T
 }
 
-impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy + Default> Vector3<T> {
-    pub fn from_vector2(vector2: Vector2<T>) -> Vector3<T> {
-        Vector3::<T> {
-            x: vector2.x,
-            y: vector2.y,
-            z: T::default()
-        }
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy> Deref for Vector3<T> {
+    type Target = XYZ<T>;
+
+    fn deref(&self) -> &XYZ<T> {
+        // SAFETY: Both the vector and XYZ are "repr(C)" and consist of exactly 3 T's without padding, so they have the same layout.
+        unsafe { &*(self as *const Vector3<T> as *const XYZ<T>) }
     }
 }
 
-impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy> Vector3<T> {
-    pub fn new(x: T, y: T, z: T) -> Vector3<T> {
-        Vector3 {
-            x,
-            y,
-            z
-        }
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy> DerefMut for Vector3<T> {
+    fn deref_mut(&mut self) -> &mut XYZ<T> {
+        // SAFETY: See deref
+        unsafe { &mut *(self as *mut Vector3<T> as *mut XYZ<T>) }
     }
+}
 
-    pub fn scalar_multiplication(self, scalar: T) -> Self {
-        Vector3 {
-            x: self.x * scalar,
-            y: self.y * scalar,
-            z: self.z * scalar
-        }
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy + Default> Vector3<T> {
+    pub fn from_vector2(vector2: Vector2<T>) -> Vector3<T> {
+        Vector3::new(vector2.x, vector2.y, T::default())
     }
+}
 
-    pub fn dot_product(self, other: Self) -> T {
-        self.x * other.x + self.y * other.y + self.z * other.z
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy> Vector3<T> {
+    pub fn new(x: T, y: T, z: T) -> Vector3<T> {
+        Vector::from_array([x, y, z])
     }
-}
 
-impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy + Sqrt> Vector3<T> {
-    pub fn length(self) -> T {
-        (self.x * self.x + self.y * self.y + self.z * self.z).sqrt()
+    pub fn cross_product(self, other: Self) -> Self {
+        Vector3::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x)
     }
 }
 
-impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy> Add for Vector3<T> {
-    type Output = Self;
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy> From<(T, T, T)> for Vector3<T> {
+    fn from(tuple: (T, T, T)) -> Vector3<T> {
+        let (x, y, z) = tuple;
 
-    fn add(self, other: Self) -> Self {
-        Vector3 {
-            x: self.x + other.x,
-            y: self.y + other.y,
-            z: self.z + other.z
-        }
+        Vector3::new(x, y, z)
     }
 }
 
-impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy> Sub for Vector3<T> {
-    type Output = Self;
-
-    fn sub(self, other: Vector3<T>) -> Self {
-        Vector3 {
-            x: self.x - other.x,
-            y: self.y - other.y,
-            z: self.z - other.z
-        }
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy> From<Vector3<T>> for (T, T, T) {
+    fn from(vector: Vector3<T>) -> (T, T, T) {
+        (vector.x, vector.y, vector.z)
     }
 }
 
@@ -132,6 +119,21 @@ mod tests {
         assert_eq!(dot_product, 7.0);
     }
 
+    #[test]
+    fn test_cross_product() {
+        // Arrange
+        let vector_a = Vector3::<f64>::new(1.0, 0.0, 0.0);
+        let vector_b = Vector3::<f64>::new(0.0, 1.0, 0.0);
+
+        // Act
+        let cross_product = vector_a.cross_product(vector_b);
+
+        // Assert
+        assert_eq!(cross_product.x, 0.0);
+        assert_eq!(cross_product.y, 0.0);
+        assert_eq!(cross_product.z, 1.0);
+    }
+
     #[test]
     fn test_scalar_multiplication() {
         // Arrange
@@ -176,4 +178,253 @@ mod tests {
         assert_eq!(subtraction_result.y, 2.0);
         assert_eq!(subtraction_result.z, 1.0);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_min_max_clamp() {
+        // Arrange
+        let vector_a = Vector3::<f64>::new(1.0, 5.0, -3.0);
+        let vector_b = Vector3::<f64>::new(2.0, 4.0, -4.0);
+
+        // Act
+        let min_result = vector_a.min(vector_b);
+        let max_result = vector_a.max(vector_b);
+        let clamp_result = vector_a.clamp(Vector3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 1.0, 1.0));
+
+        // Assert
+        assert_eq!(min_result.x, 1.0);
+        assert_eq!(min_result.y, 4.0);
+        assert_eq!(max_result.x, 2.0);
+        assert_eq!(max_result.y, 5.0);
+        assert_eq!(clamp_result.x, 1.0);
+        assert_eq!(clamp_result.y, 1.0);
+    }
+
+    #[test]
+    fn test_rounding_functions() {
+        // Arrange
+        let vector_a = Vector3::<f64>::new(1.5, -2.5, 3.0);
+
+        // Act
+        let abs_result = vector_a.abs();
+        let floor_result = vector_a.floor();
+        let ceil_result = vector_a.ceil();
+        let round_result = vector_a.round();
+        let fract_result = vector_a.fract();
+
+        // Assert
+        assert_eq!(abs_result.y, 2.5);
+        assert_eq!(floor_result.x, 1.0);
+        assert_eq!(floor_result.y, -3.0);
+        assert_eq!(ceil_result.x, 2.0);
+        assert_eq!(ceil_result.y, -2.0);
+        assert_eq!(round_result.x, 2.0);
+        assert_eq!(round_result.y, -3.0);
+        assert_eq!(fract_result.x, 0.5);
+        assert_eq!(fract_result.y, 0.5);
+    }
+
+    #[test]
+    fn test_integer_vector_as_hash_map_key() {
+        // Arrange
+        let mut tiles = std::collections::HashMap::new();
+        tiles.insert(Vector3::new(1, 2, 3), "grass");
+
+        // Act
+        let tile = tiles.get(&Vector3::new(1, 2, 3));
+
+        // Assert
+        assert_eq!(tile, Some(&"grass"));
+    }
+
+    #[test]
+    fn test_ordering() {
+        // Arrange
+        let vector_a = Vector3::new(1, 5, 5);
+        let vector_b = Vector3::new(2, 0, 0);
+
+        // Act & Assert
+        assert!(vector_a < vector_b);
+        assert_eq!(Ord::max(vector_a, vector_b), vector_b);
+    }
+
+    #[test]
+    fn test_manhattan_and_chebyshev_length() {
+        // Arrange
+        let vector_a = Vector3::<i32>::new(-3, 2, -1);
+
+        // Act
+        let manhattan_length = vector_a.manhattan_length();
+        let chebyshev_length = vector_a.chebyshev_length();
+
+        // Assert
+        assert_eq!(manhattan_length, 6);
+        assert_eq!(chebyshev_length, 3);
+    }
+
+    #[test]
+    fn test_overflow_arithmetic() {
+        // Arrange
+        let vector_a = Vector3::<u8>::new(250, 1, 1);
+        let vector_b = Vector3::<u8>::new(10, 1, 1);
+
+        // Act
+        let checked_result = vector_a.checked_add(vector_b);
+        let saturating_result = vector_a.saturating_add(vector_b);
+        let wrapping_result = vector_a.wrapping_add(vector_b);
+
+        // Assert
+        assert_eq!(checked_result, None);
+        assert_eq!(saturating_result.x, 255);
+        assert_eq!(wrapping_result.x, 4);
+        assert_eq!(wrapping_result.y, 2);
+    }
+
+    #[test]
+    fn test_remainder_and_shifts() {
+        // Arrange
+        let vector_a = Vector3::<i32>::new(7, -7, 8);
+
+        // Act
+        let remainder = vector_a % Vector3::new(4, 4, 4);
+        let shifted_left = vector_a << 1;
+        let shifted_right = vector_a >> 1;
+
+        // Assert
+        assert_eq!(remainder.x, 3);
+        assert_eq!(remainder.y, -3);
+        assert_eq!(shifted_left.x, 14);
+        assert_eq!(shifted_right.x, 3);
+    }
+
+    #[test]
+    fn test_casting() {
+        // Arrange
+        let integer_vector = Vector3::<i32>::new(1, -2, 3);
+
+        // Act
+        let converted_to_float = integer_vector.to_f32();
+        let converted_to_integer = Vector3::<f32>::new(1.5, -2.5, 3.0).try_to_i32();
+
+        // Assert
+        assert_eq!(converted_to_float.y, -2.0);
+        assert_eq!(converted_to_integer, Some(Vector3::new(1, -2, 3)));
+        assert_eq!(Vector3::<f32>::new(1.5, 2.5, 3.0).try_to_u32(), Some(Vector3::new(1, 2, 3)));
+
+        // Each vector has a single component that cannot be converted, so every case is checked on its own
+        assert_eq!(Vector3::<f32>::new(1.5, -2.5, 3.0).try_to_u32(), None);
+        assert_eq!(Vector3::<f32>::new(1.5, 2.5, f32::NAN).try_to_u32(), None);
+        assert_eq!(Vector3::<f32>::new(1.5, 2.5, f32::NAN).try_to_i32(), None);
+        assert_eq!(Vector3::<f32>::new(1.5, 1e10, 3.0).try_to_u32(), None);
+        assert_eq!(Vector3::<f32>::new(1.5, 1e10, 3.0).try_to_i32(), None);
+    }
+
+    #[test]
+    fn test_display() {
+        // Arrange
+        let vector_a = Vector3::new(1.0, -2.5, 3.25);
+
+        // Act
+        let display = format!("{}", vector_a);
+        let display_with_precision = format!("{:.1}", vector_a);
+        let display_with_width = format!("{:5.2}", vector_a);
+
+        // Assert
+        assert_eq!(display, "(1, -2.5, 3.25)");
+        assert_eq!(display_with_precision, "(1.0, -2.5, 3.2)");
+        assert_eq!(display_with_width, "( 1.00, -2.50,  3.25)");
+    }
+
+    #[test]
+    fn test_debug() {
+        // Arrange
+        let vector_a = Vector3::new(1.0, -2.5, 3.25);
+
+        // Act
+        let debug = format!("{:?}", vector_a);
+        let pretty_debug = format!("{:#?}", vector_a);
+
+        // Assert
+        assert_eq!(debug, "Vector3 { x: 1.0, y: -2.5, z: 3.25 }");
+        assert_eq!(pretty_debug.lines().count(), 5);
+    }
+
+    #[test]
+    fn test_from_str() {
+        // Act
+        let parsed_vector = "1.0, 2.0, 3.0".parse::<Vector3<f32>>().unwrap();
+
+        // Assert
+        assert_eq!(parsed_vector, Vector3::new(1.0, 2.0, 3.0));
+    }
+
+    #[test]
+    fn test_display_round_trip() {
+        // Arrange
+        let vector_a = Vector3::<f32>::new(0.1, -2.5, 3e-7);
+
+        // Act
+        let parsed_vector: Vector3<f32> = vector_a.to_string().parse().unwrap();
+
+        // Assert
+        assert_eq!(parsed_vector, vector_a);
+    }
+
+    #[test]
+    fn test_from_str_error() {
+        // Act
+        let error = "1.0, oops, 3.0".parse::<Vector3<f32>>().unwrap_err();
+
+        // Assert
+        assert_eq!(error.span(), 5..9);
+    }
+
+    #[test]
+    fn test_memory_layout() {
+        // Act & Assert
+        assert_eq!(std::mem::size_of::<Vector3<f32>>(), 12);
+        assert_eq!(std::mem::align_of::<Vector3<f32>>(), 4);
+        assert_eq!(std::mem::size_of::<Vector3<f64>>(), 24);
+        assert_eq!(std::mem::size_of::<Vector3<u8>>(), 3);
+        assert_eq!(std::mem::offset_of!(XYZ<f32>, x), 0);
+        assert_eq!(std::mem::offset_of!(XYZ<f32>, y), 4);
+        assert_eq!(std::mem::offset_of!(XYZ<f32>, z), 8);
+    }
+
+    #[test]
+    fn test_array_and_tuple_conversions() {
+        // Arrange
+        let vector_a = Vector3::<i32>::new(1, 2, 3);
+
+        // Act
+        let array: [i32; 3] = vector_a.into();
+        let tuple: (i32, i32, i32) = vector_a.into();
+
+        // Assert
+        assert_eq!(array, [1, 2, 3]);
+        assert_eq!(tuple, (1, 2, 3));
+        assert_eq!(Vector3::from(array), vector_a);
+        assert_eq!(Vector3::from(tuple), vector_a);
+    }
+
+    #[test]
+    fn test_flat_slice_views() {
+        // Arrange
+        let mut vectors = [Vector3::<f32>::new(1.0, 2.0, 3.0), Vector3::<f32>::new(4.0, 5.0, 6.0)];
+        let components = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+
+        // Act
+        let flat_slice = Vector3::as_flat_slice(&vectors);
+        let vector_slice = Vector3::from_flat_slice(&components).unwrap();
+
+        // Assert
+        assert_eq!(flat_slice, &components);
+        assert_eq!(vector_slice, &vectors);
+        assert!(Vector3::from_flat_slice(&components[1..]).is_none());
+
+        // Act
+        Vector3::as_flat_slice_mut(&mut vectors)[3] = -1.0;
+
+        // Assert
+        assert_eq!(vectors[1].x, -1.0);
+    }
+}
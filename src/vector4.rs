@@ -0,0 +1,398 @@
+use crate::vector3::Vector3;
+use core::ops::{Add, Sub, Mul, Deref, DerefMut};
+
+use crate::matrix::Vector;
+
+// Four-component vector, mostly useful for homogeneous coordinates where the "w" component
+// distinguishes points (w = 1) from directions (w = 0), and for the result of a projection before the division by w.
+// Like Vector2 and Vector3, it is the generic Vector of its size.
+pub type Vector4<T> = Vector<T, 4>;
+
+// The components of a Vector4 by name, reached through Deref as "vector.x", "vector.y", "vector.z" and "vector.w"
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+#[repr(C)]
+pub struct XYZW<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+    pub w: T
+}
+
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy> Deref for Vector4<T> {
+    type Target = XYZW<T>;
+
+    fn deref(&self) -> &XYZW<T> {
+        // SAFETY: Both the vector and XYZW are "repr(C)" and consist of exactly 4 T's without padding, so they have the same layout.
+        unsafe { &*(self as *const Vector4<T> as *const XYZW<T>) }
+    }
+}
+
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy> DerefMut for Vector4<T> {
+    fn deref_mut(&mut self) -> &mut XYZW<T> {
+        // SAFETY: See deref
+        unsafe { &mut *(self as *mut Vector4<T> as *mut XYZW<T>) }
+    }
+}
+
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy> Vector4<T> {
+    pub fn new(x: T, y: T, z: T, w: T) -> Vector4<T> {
+        Vector::from_array([x, y, z, w])
+    }
+
+    pub fn from_vector3(vector3: Vector3<T>, w: T) -> Vector4<T> {
+        Vector4::new(vector3.x, vector3.y, vector3.z, w)
+    }
+}
+
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy> From<(T, T, T, T)> for Vector4<T> {
+    fn from(tuple: (T, T, T, T)) -> Vector4<T> {
+        let (x, y, z, w) = tuple;
+
+        Vector4::new(x, y, z, w)
+    }
+}
+
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy> From<Vector4<T>> for (T, T, T, T) {
+    fn from(vector: Vector4<T>) -> (T, T, T, T) {
+        (vector.x, vector.y, vector.z, vector.w)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use float_cmp::approx_eq;
+
+    #[test]
+    fn test_construction() {
+        // Act
+        let vector_a = Vector4::<f64>::new(1.0, 2.0, 3.0, 4.0);
+
+        // Assert
+        assert_eq!(vector_a.x, 1.0);
+        assert_eq!(vector_a.y, 2.0);
+        assert_eq!(vector_a.z, 3.0);
+        assert_eq!(vector_a.w, 4.0);
+    }
+
+    #[test]
+    fn test_from_vector3() {
+        // Arrange
+        let vector_a = Vector3::<f64>::new(1.0, 2.0, 3.0);
+
+        // Act
+        let constructed_vector4 = Vector4::from_vector3(vector_a, 1.0);
+
+        // Assert
+        assert_eq!(constructed_vector4.x, 1.0);
+        assert_eq!(constructed_vector4.y, 2.0);
+        assert_eq!(constructed_vector4.z, 3.0);
+        assert_eq!(constructed_vector4.w, 1.0);
+    }
+
+    #[test]
+    fn test_length() {
+        // Arrange
+        let vector_a = Vector4::<f64>::new(1.0, 2.0, 3.0, 4.0);
+
+        // Act
+        let vector_length = vector_a.length();
+
+        // Assert
+        assert!( approx_eq!(f64, vector_length, 5.48, epsilon = 0.01) );
+    }
+
+    #[test]
+    fn test_dot_product() {
+        // Arrange
+        let vector_a = Vector4::<f64>::new(1.0, 2.0, 1.0, 2.0);
+        let vector_b = Vector4::<f64>::new(2.0, 1.0, 3.0, 1.0);
+
+        // Act
+        let dot_product = vector_a.dot_product(vector_b);
+
+        // Assert
+        assert_eq!(dot_product, 9.0);
+    }
+
+    #[test]
+    fn test_scalar_multiplication() {
+        // Arrange
+        let vector_a = Vector4::<f64>::new(2.0, 3.0, 4.0, 5.0);
+
+        // Act
+        let scalar_multiplication_result = vector_a.scalar_multiplication(2.0);
+
+        // Assert
+        assert_eq!(scalar_multiplication_result.x, 4.0);
+        assert_eq!(scalar_multiplication_result.y, 6.0);
+        assert_eq!(scalar_multiplication_result.z, 8.0);
+        assert_eq!(scalar_multiplication_result.w, 10.0);
+    }
+
+    #[test]
+    fn test_add_and_subtract() {
+        // Arrange
+        let vector_a = Vector4::<f64>::new(1.0, 2.0, 3.0, 4.0);
+        let vector_b = Vector4::<f64>::new(2.0, 2.0, 2.0, 2.0);
+
+        // Act
+        let addition_result = vector_a + vector_b;
+        let subtraction_result = vector_a - vector_b;
+
+        // Assert
+        assert_eq!(addition_result.w, 6.0);
+        assert_eq!(subtraction_result.x, -1.0);
+        assert_eq!(subtraction_result.w, 2.0);
+    }
+
+    #[test]
+    fn test_min_max_clamp() {
+        // Arrange
+        let vector_a = Vector4::<f64>::new(1.0, 5.0, -3.0, 2.0);
+        let vector_b = Vector4::<f64>::new(2.0, 4.0, -4.0, 2.0);
+
+        // Act
+        let min_result = vector_a.min(vector_b);
+        let max_result = vector_a.max(vector_b);
+        let clamp_result = vector_a.clamp(Vector4::new(0.0, 0.0, 0.0, 0.0), Vector4::new(1.0, 1.0, 1.0, 1.0));
+
+        // Assert
+        assert_eq!(min_result.x, 1.0);
+        assert_eq!(min_result.y, 4.0);
+        assert_eq!(max_result.x, 2.0);
+        assert_eq!(max_result.y, 5.0);
+        assert_eq!(clamp_result.x, 1.0);
+        assert_eq!(clamp_result.y, 1.0);
+    }
+
+    #[test]
+    fn test_rounding_functions() {
+        // Arrange
+        let vector_a = Vector4::<f64>::new(1.5, -2.5, 3.0, -0.25);
+
+        // Act
+        let abs_result = vector_a.abs();
+        let floor_result = vector_a.floor();
+        let ceil_result = vector_a.ceil();
+        let round_result = vector_a.round();
+        let fract_result = vector_a.fract();
+
+        // Assert
+        assert_eq!(abs_result.y, 2.5);
+        assert_eq!(floor_result.x, 1.0);
+        assert_eq!(floor_result.y, -3.0);
+        assert_eq!(ceil_result.x, 2.0);
+        assert_eq!(ceil_result.y, -2.0);
+        assert_eq!(round_result.x, 2.0);
+        assert_eq!(round_result.y, -3.0);
+        assert_eq!(fract_result.x, 0.5);
+        assert_eq!(fract_result.y, 0.5);
+    }
+
+    #[test]
+    fn test_integer_vector_as_hash_map_key() {
+        // Arrange
+        let mut tiles = std::collections::HashMap::new();
+        tiles.insert(Vector4::new(1, 2, 3, 4), "grass");
+
+        // Act
+        let tile = tiles.get(&Vector4::new(1, 2, 3, 4));
+
+        // Assert
+        assert_eq!(tile, Some(&"grass"));
+    }
+
+    #[test]
+    fn test_ordering() {
+        // Arrange
+        let vector_a = Vector4::new(1, 5, 5, 5);
+        let vector_b = Vector4::new(2, 0, 0, 0);
+
+        // Act & Assert
+        assert!(vector_a < vector_b);
+        assert_eq!(Ord::max(vector_a, vector_b), vector_b);
+    }
+
+    #[test]
+    fn test_manhattan_and_chebyshev_length() {
+        // Arrange
+        let vector_a = Vector4::<i32>::new(-3, 2, -1, 4);
+
+        // Act
+        let manhattan_length = vector_a.manhattan_length();
+        let chebyshev_length = vector_a.chebyshev_length();
+
+        // Assert
+        assert_eq!(manhattan_length, 10);
+        assert_eq!(chebyshev_length, 4);
+    }
+
+    #[test]
+    fn test_overflow_arithmetic() {
+        // Arrange
+        let vector_a = Vector4::<u8>::new(250, 1, 1, 1);
+        let vector_b = Vector4::<u8>::new(10, 1, 1, 1);
+
+        // Act
+        let checked_result = vector_a.checked_add(vector_b);
+        let saturating_result = vector_a.saturating_add(vector_b);
+        let wrapping_result = vector_a.wrapping_add(vector_b);
+
+        // Assert
+        assert_eq!(checked_result, None);
+        assert_eq!(saturating_result.x, 255);
+        assert_eq!(wrapping_result.x, 4);
+        assert_eq!(wrapping_result.y, 2);
+    }
+
+    #[test]
+    fn test_remainder_and_shifts() {
+        // Arrange
+        let vector_a = Vector4::<i32>::new(7, -7, 8, 9);
+
+        // Act
+        let remainder = vector_a % Vector4::new(4, 4, 4, 4);
+        let shifted_left = vector_a << 1;
+        let shifted_right = vector_a >> 1;
+
+        // Assert
+        assert_eq!(remainder.x, 3);
+        assert_eq!(remainder.y, -3);
+        assert_eq!(shifted_left.x, 14);
+        assert_eq!(shifted_right.x, 3);
+    }
+
+    #[test]
+    fn test_casting() {
+        // Arrange
+        let integer_vector = Vector4::<i32>::new(1, -2, 3, 4);
+
+        // Act
+        let converted_to_float = integer_vector.to_f32();
+        let converted_to_integer = Vector4::<f32>::new(1.5, -2.5, 3.0, 4.0).try_to_i32();
+
+        // Assert
+        assert_eq!(converted_to_float.y, -2.0);
+        assert_eq!(converted_to_integer, Some(Vector4::new(1, -2, 3, 4)));
+        assert_eq!(Vector4::<f32>::new(1.5, 2.5, 3.0, 4.0).try_to_u32(), Some(Vector4::new(1, 2, 3, 4)));
+
+        // Each vector has a single component that cannot be converted, so every case is checked on its own
+        assert_eq!(Vector4::<f32>::new(1.5, -2.5, 3.0, 4.0).try_to_u32(), None);
+        assert_eq!(Vector4::<f32>::new(1.5, 2.5, f32::NAN, 4.0).try_to_u32(), None);
+        assert_eq!(Vector4::<f32>::new(1.5, 2.5, f32::NAN, 4.0).try_to_i32(), None);
+        assert_eq!(Vector4::<f32>::new(1.5, 1e10, 3.0, 4.0).try_to_u32(), None);
+        assert_eq!(Vector4::<f32>::new(1.5, 1e10, 3.0, 4.0).try_to_i32(), None);
+    }
+
+    #[test]
+    fn test_display() {
+        // Arrange
+        let vector_a = Vector4::new(1.0, -2.5, 3.25, 4.0);
+
+        // Act
+        let display = format!("{}", vector_a);
+        let display_with_precision = format!("{:.1}", vector_a);
+        let display_with_width = format!("{:5.2}", vector_a);
+
+        // Assert
+        assert_eq!(display, "(1, -2.5, 3.25, 4)");
+        assert_eq!(display_with_precision, "(1.0, -2.5, 3.2, 4.0)");
+        assert_eq!(display_with_width, "( 1.00, -2.50,  3.25,  4.00)");
+    }
+
+    #[test]
+    fn test_debug() {
+        // Arrange
+        let vector_a = Vector4::new(1.0, -2.5, 3.25, 4.0);
+
+        // Act
+        let debug = format!("{:?}", vector_a);
+        let pretty_debug = format!("{:#?}", vector_a);
+
+        // Assert
+        assert_eq!(debug, "Vector4 { x: 1.0, y: -2.5, z: 3.25, w: 4.0 }");
+        assert_eq!(pretty_debug.lines().count(), 6);
+    }
+
+    #[test]
+    fn test_from_str() {
+        // Act
+        let parsed_vector = "1.0, 2.0, 3.0, 4.0".parse::<Vector4<f32>>().unwrap();
+
+        // Assert
+        assert_eq!(parsed_vector, Vector4::new(1.0, 2.0, 3.0, 4.0));
+    }
+
+    #[test]
+    fn test_display_round_trip() {
+        // Arrange
+        let vector_a = Vector4::<f32>::new(0.1, -2.5, 3e-7, 4.0);
+
+        // Act
+        let parsed_vector: Vector4<f32> = vector_a.to_string().parse().unwrap();
+
+        // Assert
+        assert_eq!(parsed_vector, vector_a);
+    }
+
+    #[test]
+    fn test_from_str_error() {
+        // Act
+        let error = "1.0, oops, 3.0, 4.0".parse::<Vector4<f32>>().unwrap_err();
+
+        // Assert
+        assert_eq!(error.span(), 5..9);
+    }
+
+    #[test]
+    fn test_memory_layout() {
+        // Act & Assert
+        assert_eq!(std::mem::size_of::<Vector4<f32>>(), 16);
+        assert_eq!(std::mem::align_of::<Vector4<f32>>(), 4);
+        assert_eq!(std::mem::size_of::<Vector4<f64>>(), 32);
+        assert_eq!(std::mem::size_of::<Vector4<u8>>(), 4);
+        assert_eq!(std::mem::offset_of!(XYZW<f32>, x), 0);
+        assert_eq!(std::mem::offset_of!(XYZW<f32>, y), 4);
+        assert_eq!(std::mem::offset_of!(XYZW<f32>, z), 8);
+        assert_eq!(std::mem::offset_of!(XYZW<f32>, w), 12);
+    }
+
+    #[test]
+    fn test_array_and_tuple_conversions() {
+        // Arrange
+        let vector_a = Vector4::<i32>::new(1, 2, 3, 4);
+
+        // Act
+        let array: [i32; 4] = vector_a.into();
+        let tuple: (i32, i32, i32, i32) = vector_a.into();
+
+        // Assert
+        assert_eq!(array, [1, 2, 3, 4]);
+        assert_eq!(tuple, (1, 2, 3, 4));
+        assert_eq!(Vector4::from(array), vector_a);
+        assert_eq!(Vector4::from(tuple), vector_a);
+    }
+
+    #[test]
+    fn test_flat_slice_views() {
+        // Arrange
+        let mut vectors = [Vector4::<f32>::new(1.0, 2.0, 3.0, 4.0), Vector4::<f32>::new(5.0, 6.0, 7.0, 8.0)];
+        let components = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0];
+
+        // Act
+        let flat_slice = Vector4::as_flat_slice(&vectors);
+        let vector_slice = Vector4::from_flat_slice(&components).unwrap();
+
+        // Assert
+        assert_eq!(flat_slice, &components);
+        assert_eq!(vector_slice, &vectors);
+        assert!(Vector4::from_flat_slice(&components[1..]).is_none());
+
+        // Act
+        Vector4::as_flat_slice_mut(&mut vectors)[4] = -1.0;
+
+        // Assert
+        assert_eq!(vectors[1].x, -1.0);
+    }
+}
@@ -0,0 +1,213 @@
+use core::fmt;
+
+use crate::vector2::Vector2;
+use crate::vector3::Vector3;
+use crate::vector4::Vector4;
+use crate::matrix4x4::Matrix4x4;
+use crate::frustum::ClipConvention;
+use crate::ray::Ray;
+
+// Where the window coordinate system has its origin.
+// OpenGL puts it in the bottom-left corner with y pointing up,
+// while most windowing systems (and Direct3D, Vulkan and Metal) put it in the top-left corner with y pointing down.
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WindowOrigin {
+    BottomLeft,
+    TopLeft
+}
+
+// Describes the rectangle of the window that normalized device coordinates are mapped to,
+// and the range of depth values that the near and far planes are mapped to.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Viewport {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+    pub min_depth: f32,
+    pub max_depth: f32,
+    pub origin: WindowOrigin,
+    pub clip_convention: ClipConvention
+}
+
+impl Viewport {
+    // Creates a viewport with the OpenGL defaults: a depth range of 0 to 1, y pointing up and clip space z between -1 and 1.
+    pub fn new(x: f32, y: f32, width: f32, height: f32) -> Viewport {
+        Viewport {
+            x,
+            y,
+            width,
+            height,
+            min_depth: 0.0,
+            max_depth: 1.0,
+            origin: WindowOrigin::BottomLeft,
+            clip_convention: ClipConvention::NegativeOneToOne
+        }
+    }
+
+    // The matrix transforming normalized device coordinates into window coordinates.
+    pub fn matrix(&self) -> Matrix4x4 {
+        let half_width = self.width / 2.0;
+        let half_height = self.height / 2.0;
+
+        let y_scale = match self.origin {
+            WindowOrigin::BottomLeft => half_height,
+            WindowOrigin::TopLeft => -half_height
+        };
+
+        let (z_scale, z_offset) = match self.clip_convention {
+            ClipConvention::NegativeOneToOne => ((self.max_depth - self.min_depth) / 2.0, (self.max_depth + self.min_depth) / 2.0),
+            ClipConvention::ZeroToOne => (self.max_depth - self.min_depth, self.min_depth)
+        };
+
+        Matrix4x4::new(
+            half_width, 0.0,     0.0,     self.x + half_width,
+            0.0,        y_scale, 0.0,     self.y + half_height,
+            0.0,        0.0,     z_scale, z_offset,
+            0.0,        0.0,     0.0,     1.0)
+    }
+}
+
+impl fmt::Display for Viewport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "position ")?;
+        fmt::Display::fmt(&Vector2::new(self.x, self.y), f)?;
+        write!(f, ", size ")?;
+        fmt::Display::fmt(&Vector2::new(self.width, self.height), f)?;
+        write!(f, ", depth range ")?;
+        fmt::Display::fmt(&Vector2::new(self.min_depth, self.max_depth), f)
+    }
+}
+
+// Maps a point in world space to window coordinates, with the depth in the z component.
+// Points in the plane of the camera (w = 0 after projection) have no defined position on the screen, and produce infinite or NaN components.
+pub fn project(point: Vector3<f32>, view_projection: &Matrix4x4, viewport: &Viewport) -> Vector3<f32> {
+    let clip = view_projection.mul_vector4(Vector4::from_vector3(point, 1.0));
+    let normalized_device = Vector3::new(clip.x / clip.w, clip.y / clip.w, clip.z / clip.w);
+
+    viewport.matrix().transform_point(normalized_device)
+}
+
+// Maps window coordinates, with the depth in the z component, back to a point in world space.
+// Returns None if the view-projection matrix cannot be inverted.
+pub fn unproject(screen: Vector3<f32>, view_projection: &Matrix4x4, viewport: &Viewport) -> Option<Vector3<f32>> {
+    let inverse = viewport.matrix().mul(*view_projection).inverse()?;
+    let world = inverse.mul_vector4(Vector4::from_vector3(screen, 1.0));
+
+    if world.w == 0.0 {
+        return None;
+    }
+
+    Some(Vector3::new(world.x / world.w, world.y / world.w, world.z / world.w))
+}
+
+// Constructs the ray in world space going through the given window position, as used for mouse picking.
+// The ray starts on the near plane, and its direction is normalized and points towards the far plane.
+pub fn screen_ray(screen: Vector2<f32>, view_projection: &Matrix4x4, viewport: &Viewport) -> Option<Ray<f32>> {
+    let near = unproject(Vector3::new(screen.x, screen.y, viewport.min_depth), view_projection, viewport)?;
+    let far = unproject(Vector3::new(screen.x, screen.y, viewport.max_depth), view_projection, viewport)?;
+
+    let direction = far - near;
+    let length = direction.length();
+
+    if length == 0.0 {
+        return None;
+    }
+
+    Some(Ray::new(near, direction.scalar_multiplication(1.0 / length)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use float_cmp::approx_eq;
+
+    fn view_projection() -> Matrix4x4 {
+        Matrix4x4::orthographic(0.0, 800.0, 0.0, 600.0, -1.0, 1.0)
+    }
+
+    #[test]
+    fn test_viewport_matrix() {
+        // Arrange
+        let viewport = Viewport::new(10.0, 20.0, 800.0, 600.0);
+
+        // Act
+        let matrix = viewport.matrix();
+        let bottom_left_near = matrix.transform_point(Vector3::new(-1.0, -1.0, -1.0));
+        let top_right_far = matrix.transform_point(Vector3::new(1.0, 1.0, 1.0));
+
+        // Assert
+        assert_eq!(bottom_left_near.x, 10.0);
+        assert_eq!(bottom_left_near.y, 20.0);
+        assert_eq!(bottom_left_near.z, 0.0);
+        assert_eq!(top_right_far.x, 810.0);
+        assert_eq!(top_right_far.y, 620.0);
+        assert_eq!(top_right_far.z, 1.0);
+    }
+
+    #[test]
+    fn test_display() {
+        // Arrange
+        let viewport = Viewport::new(0.0, 0.0, 800.0, 600.0);
+
+        // Act
+        let display = format!("{}", viewport);
+
+        // Assert
+        assert_eq!(display, "position (0, 0), size (800, 600), depth range (0, 1)");
+    }
+
+    #[test]
+    fn test_project() {
+        // Arrange
+        let mut viewport = Viewport::new(0.0, 0.0, 800.0, 600.0);
+
+        // Act
+        let y_up = project(Vector3::new(100.0, 100.0, 0.0), &view_projection(), &viewport);
+        viewport.origin = WindowOrigin::TopLeft;
+        let y_down = project(Vector3::new(100.0, 100.0, 0.0), &view_projection(), &viewport);
+
+        // Assert
+        assert!( approx_eq!(f32, y_up.x, 100.0, epsilon = 0.001) );
+        assert!( approx_eq!(f32, y_up.y, 100.0, epsilon = 0.001) );
+        assert!( approx_eq!(f32, y_up.z, 0.5, epsilon = 0.001) );
+        assert!( approx_eq!(f32, y_down.x, 100.0, epsilon = 0.001) );
+        assert!( approx_eq!(f32, y_down.y, 500.0, epsilon = 0.001) );
+    }
+
+    #[test]
+    fn test_unproject_round_trip() {
+        // Arrange
+        let mut viewport = Viewport::new(0.0, 0.0, 800.0, 600.0);
+        viewport.origin = WindowOrigin::TopLeft;
+        viewport.clip_convention = ClipConvention::ZeroToOne;
+        let point = Vector3::new(250.0, 125.0, 0.5);
+
+        // Act
+        let screen = project(point, &view_projection(), &viewport);
+        let unprojected_point = unproject(screen, &view_projection(), &viewport).unwrap();
+
+        // Assert
+        assert!( approx_eq!(f32, unprojected_point.x, point.x, epsilon = 0.001) );
+        assert!( approx_eq!(f32, unprojected_point.y, point.y, epsilon = 0.001) );
+        assert!( approx_eq!(f32, unprojected_point.z, point.z, epsilon = 0.001) );
+    }
+
+    #[test]
+    fn test_screen_ray() {
+        // Arrange
+        let viewport = Viewport::new(0.0, 0.0, 800.0, 600.0);
+
+        // Act
+        let ray = screen_ray(Vector2::new(400.0, 300.0), &view_projection(), &viewport).unwrap();
+
+        // Assert
+        assert!( approx_eq!(f32, ray.origin.x, 400.0, epsilon = 0.001) );
+        assert!( approx_eq!(f32, ray.origin.y, 300.0, epsilon = 0.001) );
+        assert!( approx_eq!(f32, ray.origin.z, -1.0, epsilon = 0.001) );
+        assert!( approx_eq!(f32, ray.direction.z, 1.0, epsilon = 0.001) );
+    }
+}